@@ -0,0 +1,141 @@
+use std::{fmt::Write as _, time::Duration};
+
+use serde::Serialize;
+
+use crate::{Error, Solution, Timings};
+
+/// A step of a solution which is timed separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Parse, Step::Part1, Step::Part2];
+
+    fn of(self, timings: &Timings) -> Option<Duration> {
+        match self {
+            Step::Parse => timings.parse,
+            Step::Part1 => timings.part_1,
+            Step::Part2 => timings.part_2,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part 1",
+            Step::Part2 => "part 2",
+        }
+    }
+}
+
+/// Timing statistics for one step of one day over repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct StepStats {
+    pub day: u32,
+    pub step: Step,
+    pub runs: usize,
+    /// In seconds.
+    #[serde(serialize_with = "as_secs")]
+    pub min: Duration,
+    /// In seconds.
+    #[serde(serialize_with = "as_secs")]
+    pub median: Duration,
+    /// In seconds.
+    #[serde(serialize_with = "as_secs")]
+    pub max: Duration,
+}
+
+fn as_secs<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Run both parts of a fresh solution on `input` `runs` times, and summarize how long each step took.
+///
+/// Steps which the solution does not time separately are omitted.
+pub fn bench(
+    day: u32,
+    solution: impl Fn() -> Result<Box<dyn Solution>, Error>,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<StepStats>, Error> {
+    assert!(runs > 0);
+    let mut timings = vec![];
+    for _ in 0..runs {
+        let mut solution = solution()?;
+        solution.part_1(input)?;
+        solution.part_2(input)?;
+        timings.push(solution.timings());
+    }
+    Ok(Step::ALL
+        .into_iter()
+        .filter_map(|step| {
+            let mut durations: Vec<Duration> = timings
+                .iter()
+                .map(|timings| step.of(timings))
+                .collect::<Option<_>>()?;
+            durations.sort_unstable();
+            Some(StepStats {
+                day,
+                step,
+                runs,
+                min: durations[0],
+                median: durations[durations.len() / 2],
+                max: durations[durations.len() - 1],
+            })
+        })
+        .collect())
+}
+
+/// Format benchmark results as a table, with a row for each step of each day.
+pub fn table(stats: &[StepStats]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>4}  {:>10}  {:>10}  {:>10}\n",
+        "day", "step", "runs", "min", "median", "max"
+    );
+    for stats in stats {
+        writeln!(
+            table,
+            "{:>3}  {:<6}  {:>4}  {:>10}  {:>10}  {:>10}",
+            stats.day,
+            stats.step.name(),
+            stats.runs,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max),
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bench, Step};
+    use crate::{Error, PreParsed, Solution};
+
+    #[test]
+    fn steps() {
+        fn solution() -> Result<Box<dyn Solution>, Error> {
+            Ok(Box::new(PreParsed::new(
+                |input: &str| input.len(),
+                |&len: &usize| len + 1,
+                |&len: &usize| len + 2,
+            )))
+        }
+        let stats = bench(1, solution, "abc", 3).unwrap();
+        assert_eq!(
+            stats.iter().map(|stats| stats.step).collect::<Vec<_>>(),
+            Step::ALL
+        );
+        assert!(stats.iter().all(|stats| stats.runs == 3
+            && stats.min <= stats.median
+            && stats.median <= stats.max));
+    }
+}
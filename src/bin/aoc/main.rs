@@ -0,0 +1,288 @@
+use std::path::{Path, PathBuf};
+
+use aoc_2023::{bench, days, runner, Params};
+
+fn usage() -> ! {
+    eprintln!("Usage: \n\tcargo aoc [DAYS...] [--part 1|2] [--offline] [--json]\n\tcargo aoc [DAYS...] --offline --param NAME=VALUE... [--part 1|2] [--json]\n\tcargo aoc [DAYS...] --bench RUNS [--param NAME=VALUE...] [--json]\n\tcargo aoc [DAYS...] --cross-check [--param NAME=VALUE...] [--part 1|2] [--json]\n\tcargo aoc [DAYS...] --graphs DIR [--mermaid]\n\tcargo aoc [DAYS...] --report [--param NAME=VALUE...]\n\nDAYS can be a day (6), an inclusive range (3..7 or 3-7), or all. Defaults to the latest day.\nWith --offline, runs against the cached inputs and checks the stored answers instead of submitting.\nWith --json, prints the results (or benchmark timings) as JSON.\nWith --bench, runs against the cached inputs RUNS times and reports how long each step took.\nWith --param, changes a puzzle parameter (e.g. --param steps=5000) of each selected day which has it; at least one must. The stored answers are not checked.\nWith --cross-check, runs every alternative implementation of the selected days' parts against the cached inputs, and reports any which disagree with the main implementation.\nWith --graphs, draws the cached inputs of the selected days which can be drawn as graphs, writing Graphviz DOT files (and Mermaid files, with --mermaid) into DIR.\nWith --report, describes how the selected days which can explain their workings solve the cached inputs.");
+    std::process::exit(2)
+}
+
+fn parse_days(arg: &str) -> Option<Vec<u32>> {
+    if arg == "all" {
+        return Some(days::DAYS.iter().map(|&(day, _)| day).collect());
+    }
+    let (start, end) = arg
+        .split_once("..=")
+        .or_else(|| arg.split_once(".."))
+        .or_else(|| arg.split_once('-'))
+        .unwrap_or((arg, arg));
+    let start = start.parse().ok()?;
+    let end = end.parse().ok()?;
+    if start > end {
+        return None;
+    }
+    Some((start..=end).collect())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut selected_days = None;
+    let mut parts = vec![];
+    let mut offline = false;
+    let mut bench_runs = None;
+    let mut json = false;
+    let mut params = Params::new();
+    let mut graphs_dir = None;
+    let mut mermaid = false;
+    let mut cross_check = false;
+    let mut report = false;
+    while let Some(arg) = args.next() {
+        match &*arg {
+            "--offline" => offline = true,
+            "--json" => json = true,
+            "--bench" => match args.next().and_then(|runs| runs.parse().ok()) {
+                Some(runs) if runs > 0 => bench_runs = Some(runs),
+                _ => usage(),
+            },
+            "--graphs" => match args.next() {
+                Some(dir) => graphs_dir = Some(PathBuf::from(dir)),
+                None => usage(),
+            },
+            "--mermaid" => mermaid = true,
+            "--cross-check" => cross_check = true,
+            "--report" => report = true,
+            "--param" => match args.next().as_deref().and_then(|arg| arg.split_once('=')) {
+                Some((name, value)) => params.insert(name, value),
+                None => usage(),
+            },
+            "--part" => match args.next().as_deref() {
+                Some("1") => parts.push(1),
+                Some("2") => parts.push(2),
+                _ => usage(),
+            },
+            _ => selected_days
+                .get_or_insert_with(Vec::new)
+                .extend(parse_days(&arg).unwrap_or_else(|| usage())),
+        }
+    }
+    let selected_days = selected_days.unwrap_or_else(|| vec![days::latest()]);
+    if let Some(dir) = graphs_dir {
+        if !parts.is_empty()
+            || offline
+            || json
+            || bench_runs.is_some()
+            || cross_check
+            || report
+            || !params.is_empty()
+        {
+            usage();
+        }
+        export_graphs(&selected_days, &dir, mermaid);
+        return;
+    }
+    if mermaid {
+        usage();
+    }
+    if let Err(error) = days::check_params(&selected_days, &params) {
+        eprintln!("{error}");
+        std::process::exit(1);
+    }
+    if report {
+        if !parts.is_empty() || offline || json || bench_runs.is_some() || cross_check {
+            usage();
+        }
+        print_reports(&selected_days, &params);
+        return;
+    }
+    if cross_check {
+        if offline || bench_runs.is_some() {
+            usage();
+        }
+        if parts.is_empty() {
+            parts = vec![1, 2];
+        }
+        run_cross_checks(&selected_days, &parts, &params, json);
+        return;
+    }
+    if let Some(runs) = bench_runs {
+        if !parts.is_empty() || offline {
+            usage();
+        }
+        run_benches(&selected_days, runs, &params, json);
+        return;
+    }
+    if !params.is_empty() && !offline {
+        usage();
+    }
+    if parts.is_empty() {
+        parts = if offline { vec![1, 2] } else { vec![2, 1] };
+    }
+
+    let mut ok = true;
+    let mut results = vec![];
+    for day in selected_days {
+        let mut solution = match days::solution(day, &params) {
+            Some(Ok(solution)) => solution,
+            Some(Err(error)) => {
+                eprintln!("Day {day}: {error}");
+                ok = false;
+                continue;
+            }
+            None => {
+                eprintln!("No solution for day {day}");
+                ok = false;
+                continue;
+            }
+        };
+        let day_results = if !params.is_empty() {
+            runner::run(day, &mut *solution, &parts)
+        } else if offline {
+            runner::verify(day, &mut *solution, &parts)
+        } else {
+            runner::submit(day, &mut *solution, &parts)
+        };
+        ok &= !day_results.iter().any(runner::PartResult::failed);
+        if json {
+            results.extend(day_results);
+        } else {
+            for result in day_results {
+                println!("{result}");
+            }
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn run_benches(selected_days: &[u32], runs: usize, params: &Params, json: bool) {
+    let mut ok = true;
+    let mut stats = vec![];
+    for &day in selected_days {
+        match days::solution(day, params) {
+            Some(Ok(_)) => {}
+            Some(Err(error)) => {
+                eprintln!("Day {day}: {error}");
+                ok = false;
+                continue;
+            }
+            None => {
+                eprintln!("No solution for day {day}");
+                ok = false;
+                continue;
+            }
+        }
+        let path = runner::input_path(day);
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {day}: could not read {}: {error}", path.display());
+                ok = false;
+                continue;
+            }
+        };
+        let solution = || days::solution(day, params).unwrap();
+        match bench::bench(day, solution, &input, runs) {
+            Ok(day_stats) => stats.extend(day_stats),
+            Err(error) => {
+                eprintln!("Day {day} failed: {error}");
+                ok = false;
+            }
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats).unwrap());
+    } else {
+        print!("{}", bench::table(&stats));
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn export_graphs(selected_days: &[u32], dir: &Path, mermaid: bool) {
+    let mut ok = true;
+    for &day in selected_days {
+        let Some(graphs) = days::graphs(day) else {
+            eprintln!("Day {day} has no graphs to draw");
+            continue;
+        };
+        match runner::export_graphs(day, graphs, dir, mermaid) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Day {day}: wrote {}", path.display());
+                }
+            }
+            Err(error) => {
+                eprintln!("Day {day}: {error}");
+                ok = false;
+            }
+        }
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn print_reports(selected_days: &[u32], params: &Params) {
+    let mut ok = true;
+    for &day in selected_days {
+        let Some(report) = days::report(day) else {
+            eprintln!("Day {day} has no report");
+            continue;
+        };
+        match runner::report(day, report, params) {
+            Ok(report) => println!("Day {day}:\n{report}"),
+            Err(error) => {
+                eprintln!("Day {day}: {error}");
+                ok = false;
+            }
+        }
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn run_cross_checks(selected_days: &[u32], parts: &[u32], params: &Params, json: bool) {
+    let mut ok = true;
+    let mut results = vec![];
+    for &day in selected_days {
+        let alternatives = days::alternatives(day);
+        if alternatives.is_empty() {
+            eprintln!("Day {day} has no alternative implementations");
+            continue;
+        }
+        let mut solution = match days::solution(day, params) {
+            Some(Ok(solution)) => solution,
+            Some(Err(error)) => {
+                eprintln!("Day {day}: {error}");
+                ok = false;
+                continue;
+            }
+            None => {
+                eprintln!("No solution for day {day}");
+                ok = false;
+                continue;
+            }
+        };
+        let day_results = runner::cross_check(day, &mut *solution, alternatives, params, parts);
+        ok &= !day_results.iter().any(runner::CrossCheckResult::failed);
+        if json {
+            results.extend(day_results);
+        } else {
+            for result in day_results {
+                println!("{result}");
+            }
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
@@ -2,12 +2,9 @@ use std::io::Write;
 
 use regex::Regex;
 
-const YEAR: u32 = 2023;
-
 const DEFAULT_TEMPLATE: &str = r###"
 #![allow(unused_imports)]
-use aoc_2023::*;
-use aoc_driver::*;
+use crate::*;
 use itertools::Itertools;
 use zachs18_stdx::*;
 
@@ -19,29 +16,19 @@ fn part_2(input: &str) -> usize {
     todo!()
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, %%YEAR%%:%%DAY%%:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    if let Err(error) = aoc_magic!(session, %%YEAR%%:%%DAY%%:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "example input";
-    assert_eq!(part_1(input), 42);
-    assert_eq!(part_2(input), 42);
+    crate::examples::check(__DAY__);
 }
 "###;
 
 const PREPARSED_TEMPLATE: &str = r###"
 #![allow(unused_imports)]
-use aoc_2023::*;
-use aoc_driver::*;
+use crate::*;
 use itertools::Itertools;
 use zachs18_stdx::*;
 
@@ -65,43 +52,33 @@ fn part_2(input: &Vec<Thing>) -> usize {
     todo!()
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    let mut both = PreParsed::new(parse, part_1, part_2);
-    let part_2 = both.part_2();
-    if let Err(error) = aoc_magic!(session, %%YEAR%%:%%DAY%%:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    let part_1 = both.part_1();
-    if let Err(error) = aoc_magic!(session, %%YEAR%%:%%DAY%%:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(PreParsed::new(parse, part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "example input";
-    let mut both = PreParsed::new(parse, part_1, part_2);
-    let part_1 = both.part_1();
-    assert_eq!(part_1(input), &42);
-    let part_2 = both.part_2();
-    assert_eq!(part_2(input), &42);
+    crate::examples::check(__DAY__);
 }
-
 "###;
 
+const EXAMPLE_INPUT: &str = "example input\n";
+const EXAMPLE_ANSWERS: &str = "{\"part_1\": 42, \"part_2\": 42}\n";
+
 fn usage() -> ! {
     eprintln!("Usage: \n\tcargo newday\n\tcargo newday 6\n\tcargo newday --preparsed\n\tcargo newday 6 --preparsed\n\tcargo newday --preparsed 6\n\t");
     std::process::exit(-1)
 }
 
+const REGISTRY_PATH: &str = "./src/days/mod.rs";
+const REGISTRY_MARKER: &str = "    // `cargo newday` inserts new days above this line.";
+
 fn find_highest_day() -> i32 {
-    let dir = std::fs::read_dir("./src/bin").unwrap();
+    let dir = std::fs::read_dir("./src/days").unwrap();
     let mut highest_day = 0;
-    let day_regex = Regex::new("^day([0-9]+)$").unwrap();
+    let day_regex = Regex::new("^day([0-9]+)\\.rs$").unwrap();
     for a in dir.map(Result::unwrap) {
-        if !a.file_type().unwrap().is_dir() {
+        if !a.file_type().unwrap().is_file() {
             continue;
         }
         let filename = a.file_name();
@@ -135,67 +112,53 @@ fn main() {
         _ => usage(),
     };
 
-    std::fs::DirBuilder::new()
-        .recursive(true)
-        .create(format!("./src/bin/day{new_day}"))
-        .expect("failed to create new day folder");
-    let real_main = std::fs::File::options()
-        .write(true)
-        .create_new(true)
-        .open(format!("./src/bin/day{new_day}/main.rs"));
-    let default_main = std::fs::File::options()
+    let module = std::fs::File::options()
         .write(true)
         .create_new(true)
-        .open(format!("./src/bin/day{new_day}/default_main.rs"));
-    let preparsed_main = std::fs::File::options()
-        .write(true)
-        .create_new(true)
-        .open(format!("./src/bin/day{new_day}/preparsed_main.rs"));
-
-    let default_formatted = DEFAULT_TEMPLATE
-        .replace("%%YEAR%%", &format!("{}", YEAR))
-        .replace("%%DAY%%", &format!("{}", new_day));
-    let preparsed_formatted = PREPARSED_TEMPLATE
-        .replace("%%YEAR%%", &format!("{}", YEAR))
-        .replace("%%DAY%%", &format!("{}", new_day));
+        .open(format!("./src/days/day{new_day}.rs"));
 
-    match real_main {
-        Ok(mut real_main) => {
+    match module {
+        Ok(mut module) => {
             let data = if use_preparsed {
-                preparsed_formatted.as_bytes()
+                PREPARSED_TEMPLATE
             } else {
-                default_formatted.as_bytes()
+                DEFAULT_TEMPLATE
             };
-            real_main
-                .write_all(data)
-                .expect("Failed to write to main.rs");
-        }
-        Err(_) => {
-            eprintln!("Warning: main.rs already exists or failed to be opened. Not modifying it.")
-        }
-    }
-
-    match default_main {
-        Ok(mut default_main) => {
-            default_main
-                .write_all(default_formatted.as_bytes())
-                .expect("Failed to write to default_main.rs");
+            module
+                .write_all(
+                    data.trim_start()
+                        .replace("__DAY__", &new_day.to_string())
+                        .as_bytes(),
+                )
+                .expect("Failed to write to day module");
         }
         Err(_) => {
             eprintln!(
-                "Warning: default_main.rs already exists or failed to be opened. Not modifying it."
-            )
+                "Warning: day{new_day}.rs already exists or failed to be opened. Not modifying it."
+            );
+            return;
         }
     }
 
-    match preparsed_main {
-        Ok(mut preparsed_main) => {
-            preparsed_main
-                .write_all(preparsed_formatted.as_bytes())
-                .expect("Failed to write to preparsed_main.rs");
-        }
-        Err(_) => {
-            eprintln!("Warning: preparsed_main.rs already exists or failed to be opened. Not modifying it.")
+    let examples = format!("./examples/day{new_day}");
+    std::fs::create_dir_all(&examples).expect("Failed to create examples directory");
+    for (file, contents) in [
+        ("example.txt", EXAMPLE_INPUT),
+        ("example.json", EXAMPLE_ANSWERS),
+    ] {
+        let path = format!("{examples}/{file}");
+        if let Ok(mut file) = std::fs::File::options()
+            .write(true)
+            .create_new(true)
+            .open(path)
+        {
+            file.write_all(contents.as_bytes())
+                .expect("Failed to write example file");
         }
     }
+
+    let registry = std::fs::read_to_string(REGISTRY_PATH).expect("Failed to read day registry");
+    let entry = format!("    {new_day} => day{new_day},\n");
+    let registry = registry.replacen(REGISTRY_MARKER, &format!("{entry}{REGISTRY_MARKER}"), 1);
+    std::fs::write(REGISTRY_PATH, registry).expect("Failed to write day registry");
 }
@@ -0,0 +1,481 @@
+use std::collections::{HashMap, VecDeque};
+
+use num_integer::Integer;
+
+use crate::{
+    diagram::{Diagram, Shape},
+    Error,
+};
+
+/// What a [`Module`] does with the pulses it receives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleKind {
+    /// Ignores high pulses, and flips between off and on on each low pulse, sending a high pulse
+    /// when it turns on and a low pulse when it turns off.
+    FlipFlop,
+    /// Remembers the last pulse from each input, and sends a low pulse if they were all high or
+    /// a high pulse otherwise.
+    Conjunction,
+    /// Sends every pulse it receives on to all of its outputs.
+    Broadcaster,
+    /// Sends a low pulse to the broadcaster when pressed.
+    Button,
+    /// Named as an output but never defined, so does nothing.
+    Untyped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub kind: ModuleKind,
+    pub inputs: Vec<usize>,
+    pub outputs: Vec<usize>,
+}
+
+/// A network of pulse-passing modules, each identified by its index in [`Circuit::modules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Circuit {
+    pub modules: Vec<Module>,
+    index: HashMap<String, usize>,
+    button: usize,
+    broadcaster: usize,
+}
+
+/// A pulse sent from one module to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub high: bool,
+}
+
+impl Circuit {
+    /// Parses lines like `%a -> b, c` (flip-flop), `&b -> c` (conjunction) and
+    /// `broadcaster -> a`. A button module connected to the broadcaster is added.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut circuit = Circuit {
+            modules: vec![],
+            index: HashMap::new(),
+            button: 0,
+            broadcaster: 0,
+        };
+        let mut connections = vec![];
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (module, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| Error::new("expected \" -> \"").at(line, input))?;
+            let (kind, name) = if let Some(name) = module.strip_prefix('%') {
+                (ModuleKind::FlipFlop, name)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (ModuleKind::Conjunction, name)
+            } else if module == "broadcaster" {
+                (ModuleKind::Broadcaster, module)
+            } else {
+                return Err(Error::new(format!("unknown module type {module:?}")).at(module, input));
+            };
+            let id = circuit.id(name);
+            if circuit.modules[id].kind != ModuleKind::Untyped {
+                return Err(Error::new(format!("module {name} defined twice")).at(module, input));
+            }
+            circuit.modules[id].kind = kind;
+            for output in outputs.split(',').map(str::trim) {
+                connections.push((id, circuit.id(output)));
+            }
+        }
+        circuit.broadcaster = circuit
+            .get("broadcaster")
+            .filter(|&id| circuit.modules[id].kind == ModuleKind::Broadcaster)
+            .ok_or_else(|| Error::new("no broadcaster"))?;
+        circuit.button = circuit.id("button");
+        if circuit.modules[circuit.button].kind != ModuleKind::Untyped {
+            return Err(Error::new("the button cannot be defined"));
+        }
+        circuit.modules[circuit.button].kind = ModuleKind::Button;
+        connections.push((circuit.button, circuit.broadcaster));
+        for (from, to) in connections {
+            circuit.modules[from].outputs.push(to);
+            circuit.modules[to].inputs.push(from);
+        }
+        Ok(circuit)
+    }
+
+    /// The ID of the module called `name`, adding an untyped module if there is none.
+    fn id(&mut self, name: &str) -> usize {
+        *self.index.entry(name.to_string()).or_insert_with(|| {
+            self.modules.push(Module {
+                name: name.to_string(),
+                kind: ModuleKind::Untyped,
+                inputs: vec![],
+                outputs: vec![],
+            });
+            self.modules.len() - 1
+        })
+    }
+
+    /// The ID of the module called `name`.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn broadcaster(&self) -> usize {
+        self.broadcaster
+    }
+
+    /// The circuit as a diagram, with flip-flops labelled `%name`, conjunctions labelled
+    /// `&name`, and untyped modules (such as `rx`) double-circled.
+    pub fn diagram(&self) -> Diagram {
+        let mut diagram = Diagram::new("circuit");
+        for module in &self.modules {
+            let name = &module.name;
+            match module.kind {
+                ModuleKind::FlipFlop => {
+                    diagram.node(name).label(format!("%{name}"));
+                }
+                ModuleKind::Conjunction => {
+                    diagram.node(name).label(format!("&{name}"));
+                }
+                ModuleKind::Broadcaster | ModuleKind::Button => {
+                    diagram.node(name).shape(Shape::Box);
+                }
+                ModuleKind::Untyped => {
+                    diagram.node(name).shape(Shape::DoubleCircle);
+                }
+            }
+            for &output in &module.outputs {
+                diagram.edge(name, &self.modules[output].name);
+            }
+        }
+        diagram
+    }
+
+    /// A simulator with every flip-flop off and every conjunction remembering low pulses.
+    pub fn simulator(&self) -> Simulator<'_> {
+        Simulator {
+            circuit: self,
+            on: vec![false; self.modules.len()],
+            remembered: self
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+            queue: VecDeque::new(),
+            presses: 0,
+        }
+    }
+
+    /// Detects the structure which makes `target` receive a low pulse: a single conjunction
+    /// feeding `target`, whose inputs are each driven by a separate sub-circuit (in practice, a
+    /// binary counter) which sends it a high pulse once every so many button presses.
+    ///
+    /// The sub-circuits' periods are found by simulation, giving up after `max_presses`.
+    pub fn analyze(&self, target: &str, max_presses: usize) -> Result<Counters, Error> {
+        let target = self
+            .get(target)
+            .ok_or_else(|| Error::new(format!("no module {target}")))?;
+        let &[conjunction] = &self.modules[target].inputs[..] else {
+            return Err(Error::new("the target does not have exactly one input"));
+        };
+        if self.modules[conjunction].kind != ModuleKind::Conjunction {
+            return Err(Error::new("the target's input is not a conjunction"));
+        }
+
+        // Each of the broadcaster's outputs should lead to exactly one of the conjunction's
+        // inputs, through modules which no other output reaches.
+        let mut owner = vec![None; self.modules.len()];
+        let mut sub_circuits = vec![];
+        for &first in &self.modules[self.broadcaster].outputs {
+            let mut modules = vec![first];
+            let mut stack = vec![first];
+            let mut inputs = vec![];
+            let index = sub_circuits.len();
+            if owner[first].is_some() {
+                return Err(Error::new(format!(
+                    "module {} is shared between sub-circuits",
+                    self.modules[first].name
+                )));
+            }
+            owner[first] = Some(index);
+            while let Some(id) = stack.pop() {
+                for &next in &self.modules[id].outputs {
+                    if next == conjunction {
+                        inputs.push(id);
+                        continue;
+                    }
+                    match owner[next] {
+                        None => {
+                            owner[next] = Some(index);
+                            modules.push(next);
+                            stack.push(next);
+                        }
+                        Some(other) if other != index => {
+                            return Err(Error::new(format!(
+                                "module {} is shared between sub-circuits",
+                                self.modules[next].name
+                            )))
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            inputs.dedup();
+            let &[output] = &inputs[..] else {
+                return Err(Error::new(format!(
+                    "the sub-circuit from {} does not feed the conjunction exactly once",
+                    self.modules[first].name
+                )));
+            };
+            sub_circuits.push(SubCircuit {
+                output,
+                modules,
+                period: 0,
+            });
+        }
+        let mut outputs = sub_circuits
+            .iter()
+            .map(|sub| sub.output)
+            .collect::<Vec<_>>();
+        let mut inputs = self.modules[conjunction].inputs.clone();
+        outputs.sort_unstable();
+        inputs.sort_unstable();
+        if outputs != inputs {
+            return Err(Error::new(
+                "the sub-circuits do not drive every input of the conjunction",
+            ));
+        }
+
+        // Find when each sub-circuit first sends a high pulse, and check it next does so after
+        // the same number of presses again.
+        let mut fired: Vec<Vec<usize>> = vec![vec![]; sub_circuits.len()];
+        let mut simulator = self.simulator();
+        while fired.iter().any(|presses| presses.len() < 2) {
+            if simulator.presses() == max_presses {
+                return Err(Error::new(format!(
+                    "the sub-circuits did not repeat within {max_presses} presses"
+                )));
+            }
+            simulator.press();
+            let presses = simulator.presses();
+            for pulse in &mut simulator {
+                if pulse.to == conjunction && pulse.high {
+                    let index = owner[pulse.from].unwrap();
+                    if fired[index].last() != Some(&presses) {
+                        fired[index].push(presses);
+                    }
+                }
+            }
+        }
+        for (sub, presses) in sub_circuits.iter_mut().zip(fired) {
+            if presses[1] != 2 * presses[0] {
+                return Err(Error::new(format!(
+                    "{} is not periodic from the start: it fired after {} and {} presses",
+                    self.modules[sub.output].name, presses[0], presses[1]
+                )));
+            }
+            sub.period = presses[0];
+        }
+        Ok(Counters {
+            conjunction,
+            sub_circuits,
+        })
+    }
+}
+
+/// The structure found by [`Circuit::analyze`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counters {
+    /// The conjunction feeding the target.
+    pub conjunction: usize,
+    pub sub_circuits: Vec<SubCircuit>,
+}
+
+/// An independent part of a circuit, between one of the broadcaster's outputs and one of the
+/// analyzed conjunction's inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubCircuit {
+    /// The module which sends the conjunction its pulses.
+    pub output: usize,
+    /// Every module in the sub-circuit.
+    pub modules: Vec<usize>,
+    /// How many button presses it takes the sub-circuit to send a high pulse.
+    pub period: usize,
+}
+
+impl Counters {
+    /// The first button press on which every sub-circuit sends a high pulse, so the conjunction
+    /// sends the target a low pulse.
+    pub fn presses(&self) -> usize {
+        self.sub_circuits
+            .iter()
+            .map(|sub| sub.period)
+            .fold(1, |a, b| a.lcm(&b))
+    }
+}
+
+/// Runs a [`Circuit`], one pulse at a time.
+///
+/// [`Simulator::press`] presses the button, and iterating yields each pulse as it is delivered,
+/// until none are left.
+#[derive(Debug, Clone)]
+pub struct Simulator<'a> {
+    circuit: &'a Circuit,
+    /// Whether each flip-flop is on.
+    on: Vec<bool>,
+    /// The last pulse each conjunction received from each of its inputs.
+    remembered: Vec<Vec<bool>>,
+    queue: VecDeque<Pulse>,
+    presses: usize,
+}
+
+impl Simulator<'_> {
+    pub fn press(&mut self) {
+        self.presses += 1;
+        self.queue.push_back(Pulse {
+            from: self.circuit.button,
+            to: self.circuit.broadcaster,
+            high: false,
+        });
+    }
+
+    /// How many times the button has been pressed.
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Deliver the next pulse, returning it, or `None` if no pulses are waiting.
+    pub fn step(&mut self) -> Option<Pulse> {
+        let pulse = self.queue.pop_front()?;
+        let module = &self.circuit.modules[pulse.to];
+        let sent = match module.kind {
+            ModuleKind::FlipFlop if !pulse.high => {
+                self.on[pulse.to] ^= true;
+                Some(self.on[pulse.to])
+            }
+            ModuleKind::FlipFlop | ModuleKind::Button | ModuleKind::Untyped => None,
+            ModuleKind::Conjunction => {
+                let remembered = &mut self.remembered[pulse.to];
+                for (input, remembered) in module.inputs.iter().zip(remembered.iter_mut()) {
+                    if *input == pulse.from {
+                        *remembered = pulse.high;
+                    }
+                }
+                Some(!remembered.iter().all(|&high| high))
+            }
+            ModuleKind::Broadcaster => Some(pulse.high),
+        };
+        if let Some(high) = sent {
+            self.queue.extend(module.outputs.iter().map(|&to| Pulse {
+                from: pulse.to,
+                to,
+                high,
+            }));
+        }
+        Some(pulse)
+    }
+}
+
+impl Iterator for Simulator<'_> {
+    type Item = Pulse;
+
+    fn next(&mut self) -> Option<Pulse> {
+        self.step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A binary counter of `bits` flip-flops called `{name}0`, `{name}1`, ..., which resets and
+    /// makes `{name}out` send `hub` a high pulse every `period` presses. `period` must be odd.
+    fn counter(name: &str, bits: usize, period: usize) -> String {
+        let mut lines = vec![];
+        for bit in 0..bits {
+            let mut outputs = vec![];
+            if bit + 1 < bits {
+                outputs.push(format!("{name}{}", bit + 1));
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(format!("{name}k"));
+            }
+            lines.push(format!("%{name}{bit} -> {}", outputs.join(", ")));
+        }
+        let mut resets = vec![format!("{name}0"), format!("{name}out")];
+        resets.extend(
+            (0..bits)
+                .filter(|bit| period & (1 << bit) == 0)
+                .map(|bit| format!("{name}{bit}")),
+        );
+        lines.push(format!("&{name}k -> {}", resets.join(", ")));
+        lines.push(format!("&{name}out -> hub"));
+        lines.join("\n")
+    }
+
+    #[test]
+    fn simulate() {
+        let circuit = Circuit::parse(
+            "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output",
+        )
+        .unwrap();
+        let mut simulator = circuit.simulator();
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..1000 {
+            simulator.press();
+            for pulse in &mut simulator {
+                if pulse.high {
+                    high += 1;
+                } else {
+                    low += 1;
+                }
+            }
+        }
+        assert_eq!((low, high), (4250, 2750));
+
+        assert!(Circuit::parse("%a -> b").is_err());
+        assert!(Circuit::parse("broadcaster -> a\n?a -> b").is_err());
+    }
+
+    #[test]
+    fn counters() {
+        let input = [
+            "broadcaster -> x0, y0".to_string(),
+            counter("x", 4, 11),
+            counter("y", 4, 13),
+            "&hub -> rx".to_string(),
+        ]
+        .join("\n");
+        let circuit = Circuit::parse(&input).unwrap();
+        let counters = circuit.analyze("rx", 1000).unwrap();
+        let periods: Vec<usize> = counters.sub_circuits.iter().map(|sub| sub.period).collect();
+        assert_eq!(periods, [11, 13]);
+        assert_eq!(counters.presses(), 143);
+
+        // Check against brute force.
+        let rx = circuit.get("rx").unwrap();
+        let mut simulator = circuit.simulator();
+        let first = (1..)
+            .find(|_| {
+                simulator.press();
+                simulator.any(|pulse| pulse.to == rx && !pulse.high)
+            })
+            .unwrap();
+        assert_eq!(first, 143);
+
+        assert!(circuit.analyze("rx", 20).is_err());
+        assert!(circuit.analyze("hub", 1000).is_err());
+
+        // The broadcaster also sends straight to a module in the first counter.
+        let shared = input.replacen("x0, y0", "x0, y0, xout", 1);
+        assert_eq!(
+            Circuit::parse(&shared)
+                .unwrap()
+                .analyze("rx", 1000)
+                .unwrap_err()
+                .to_string(),
+            "module xout is shared between sub-circuits"
+        );
+    }
+}
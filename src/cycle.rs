@@ -0,0 +1,214 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of the sequence of states produced by repeatedly stepping a state machine
+/// with finitely many states: `start` states which are never revisited, followed by a loop of
+/// `period` states which repeats forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// How many steps it takes to first reach a state in the loop.
+    pub start: usize,
+    /// How many steps it takes to get around the loop.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps which reaches the same state as `steps` steps.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle in the sequence of states starting from `initial`, remembering every state
+/// seen until the first repeated state.
+///
+/// The sequence must eventually repeat, or this never returns.
+pub fn detect_hashed<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    hashed(initial, step, usize::MAX)
+        .0
+        .expect("a sequence which repeats before usize::MAX steps")
+}
+
+/// Finds the cycle in the sequence of states starting from `initial` with Brent's algorithm,
+/// which only keeps two states at a time, but steps some states more than once.
+///
+/// The sequence must eventually repeat, or this never returns.
+pub fn detect_brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period by moving the tortoise up to the hare at each power of two,
+    // until the hare reaches the tortoise.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Then find the start with the hare `period` steps ahead of the tortoise.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Finds the cycle in the sequence of states starting from `initial` with Floyd's algorithm,
+/// which only keeps two states at a time, but steps some states more than once.
+///
+/// The sequence must eventually repeat, or this never returns.
+pub fn detect_floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find a state in the loop by moving the hare twice as fast as the tortoise.
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The hare is now a multiple of the period ahead of the start, so moving both at the same
+    // speed, with the tortoise from the beginning, they meet at the start.
+    let mut tortoise = initial;
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle { start, period }
+}
+
+/// Finds the cycle in the sequence of states starting from `initial`, and the state after
+/// `steps` steps, remembering every state seen until the first repeated state.
+///
+/// If no state repeats within `steps` steps, this stops there, without finding the cycle.
+pub fn fast_forward_hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> (Option<Cycle>, S) {
+    let (cycle, mut history) = hashed(initial, step, steps);
+    let state = history.swap_remove(cycle.map_or(steps, |cycle| cycle.reduce(steps)));
+    (cycle, state)
+}
+
+/// Like [`fast_forward_hashed`], but always finds the cycle, with [`detect_brent`], and then steps
+/// from `initial` again to find the state after `steps` steps.
+pub fn fast_forward_brent<S: Clone + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> (Cycle, S) {
+    let cycle = detect_brent(initial.clone(), &mut step);
+    (cycle, nth(initial, step, cycle.reduce(steps)))
+}
+
+/// Like [`fast_forward_hashed`], but always finds the cycle, with [`detect_floyd`], and then steps
+/// from `initial` again to find the state after `steps` steps.
+pub fn fast_forward_floyd<S: Clone + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> (Cycle, S) {
+    let cycle = detect_floyd(initial.clone(), &mut step);
+    (cycle, nth(initial, step, cycle.reduce(steps)))
+}
+
+/// The cycle, and every state up to (but not including) the first repeated state, or `None` and
+/// the states after 0 to `steps` steps if none of those repeat sooner.
+fn hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    steps: usize,
+) -> (Option<Cycle>, Vec<S>) {
+    // Map from state to how many steps it took to reach it.
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut history = vec![initial];
+    while history.len() <= steps {
+        let state = step(history.last().unwrap());
+        if let Some(&start) = seen.get(&state) {
+            let period = history.len() - start;
+            return (Some(Cycle { start, period }), history);
+        }
+        seen.insert(state.clone(), history.len());
+        history.push(state);
+    }
+    (None, history)
+}
+
+fn nth<S>(initial: S, mut step: impl FnMut(&S) -> S, steps: usize) -> S {
+    (0..steps).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies_agree() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+        let step = |&state: &u32| if state == 5 { 2 } else { state + 1 };
+        let expected = Cycle {
+            start: 2,
+            period: 4,
+        };
+        assert_eq!(detect_hashed(0, step), expected);
+        assert_eq!(detect_brent(0, step), expected);
+        assert_eq!(detect_floyd(0, step), expected);
+        for steps in [0, 1, 2, 5, 6, 1_000_000_001] {
+            let state = nth(0, step, expected.reduce(steps));
+            assert_eq!(fast_forward_hashed(0, step, steps).1, state);
+            assert_eq!(fast_forward_brent(0, step, steps), (expected, state));
+            assert_eq!(fast_forward_floyd(0, step, steps), (expected, state));
+        }
+        assert_eq!(
+            fast_forward_hashed(0, step, 1_000_000_001),
+            (Some(expected), 5)
+        );
+        // No state repeats within 5 steps, so the cycle is not found.
+        assert_eq!(fast_forward_hashed(0, step, 5), (None, 5));
+        // A sequence which never repeats can still be fast-forwarded a finite number of steps.
+        assert_eq!(
+            fast_forward_hashed(0, |&state: &u64| state + 1, 1000),
+            (None, 1000)
+        );
+
+        for initial in 0..100 {
+            let step = |&state: &u64| (state * state + 1) % 1009;
+            let cycle = detect_hashed(initial, step);
+            assert_eq!(detect_brent(initial, step), cycle);
+            assert_eq!(detect_floyd(initial, step), cycle);
+        }
+
+        let fixed = Cycle {
+            start: 0,
+            period: 1,
+        };
+        assert_eq!(detect_hashed(7, |&state: &u32| state), fixed);
+        assert_eq!(detect_brent(7, |&state: &u32| state), fixed);
+        assert_eq!(detect_floyd(7, |&state: &u32| state), fixed);
+    }
+}
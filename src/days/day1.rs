@@ -1,4 +1,4 @@
-use aoc_driver::*;
+use crate::{days::Alternative, SeparateFunctions, Solution};
 use regex::Regex;
 
 fn part_1(input: &str) -> u64 {
@@ -29,7 +29,6 @@ fn digit_value(digit: &str) -> u64 {
     }
 }
 
-#[allow(unused)]
 fn part_2_regex(input: &str) -> u64 {
     let input = input.lines().map(str::trim);
     let digit_regex = Regex::new("one|two|three|four|five|six|seven|eight|nine|[0-9]").unwrap();
@@ -100,32 +99,19 @@ fn part_2(input: &str) -> u64 {
         .sum()
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, 2023:1:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    if let Err(error) = aoc_magic!(session, 2023:1:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub const ALTERNATIVES: &[Alternative] = &[Alternative {
+    part: 2,
+    name: "regex",
+    run: |input, _| Ok(part_2_regex(input).to_string()),
+}];
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-    assert_eq!(part_1(input), 142);
-    let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-    assert_eq!(part_2(input), 281);
+    crate::examples::check(1);
 }
 
 #[test]
@@ -1,16 +1,6 @@
 use std::collections::VecDeque;
 
-use aoc_2023::*;
-use aoc_driver::*;
-use itertools::Itertools;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
+use crate::{days::Alternative, *};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Pipe {
@@ -64,89 +54,53 @@ impl Pipe {
 
 /// Parses the map, calculates the maximum distance along the loop,
 /// and clears the non-loop elements from the map.
-fn parse_etc(input: &str) -> (Vec<Box<[Option<Pipe>]>>, usize) {
-    let mut data: Vec<Box<[Option<Pipe>]>> = input
-        .lines()
-        .map(str::trim)
-        .map(str::as_bytes)
-        .map(|row| row.iter().map(|&b| Pipe::from_u8(b)).collect())
-        .collect_vec();
-    let s_loc @ (s_row, s_col) = data
-        .iter()
-        .enumerate()
-        .find_map(|(rowidx, row)| {
-            let colidx = row.iter().position(|&b| b == Some(Pipe::Source))?;
-            Some((rowidx, colidx))
-        })
-        .unwrap();
-    let h = data.len();
-    let w = data[0].len();
-    let mut distances = vec![vec![usize::MAX; w]; h];
-    distances[s_row][s_col] = 0;
-    let mut frontier = VecDeque::from([
-        (s_loc, Direction::South, 0_usize),
-        (s_loc, Direction::North, 0),
-        (s_loc, Direction::West, 0),
-        (s_loc, Direction::East, 0),
-    ]);
+fn parse_etc(input: &str) -> (Grid<Option<Pipe>>, usize) {
+    let mut data = Grid::parse_with(input, Pipe::from_u8);
+    let s_loc = data.find(&Some(Pipe::Source)).unwrap();
+    let (h, w) = data.size();
+    let mut distances = Grid::new(h, w, usize::MAX);
+    distances[s_loc] = 0;
+    let mut frontier = Direction::ALL
+        .map(|direction| (s_loc, direction, 0_usize))
+        .into_iter()
+        .collect::<VecDeque<_>>();
     while let Some((loc, dir, dist)) = frontier.pop_front() {
-        let (y, x) = loc;
-        let (y2, x2) = match dir {
-            Direction::North => (y.wrapping_sub(1), x),
-            Direction::South => (y + 1, x),
-            Direction::West => (y, x.wrapping_sub(1)),
-            Direction::East => (y, x + 1),
-        };
-        if y2 >= h || x2 >= w {
+        let Some((y2, x2)) = data.offset(loc, dir) else {
             continue;
-        }
+        };
         let newdist = dist + 1;
-        if newdist < distances[y2][x2] {
-            let Some(newdir) = data[y2][x2].and_then(|cell| cell.go_through(dir)) else {
+        if newdist < distances[(y2, x2)] {
+            let Some(newdir) = data[(y2, x2)].and_then(|cell| cell.go_through(dir)) else {
                 continue;
             };
 
-            distances[y2][x2] = newdist;
+            distances[(y2, x2)] = newdist;
             frontier.push_back(((y2, x2), newdir, newdist));
         }
     }
 
     let mut max_distance = 0;
 
-    for row in 0..h {
-        for col in 0..w {
-            if distances[row][col] == usize::MAX {
-                data[row][col] = None;
-            } else {
-                max_distance = Ord::max(distances[row][col], max_distance);
-            }
+    for (position, cell) in data.iter_mut() {
+        if distances[position] == usize::MAX {
+            *cell = None;
+        } else {
+            max_distance = Ord::max(distances[position], max_distance);
         }
     }
 
     // replace S by actual pipe
-    let north = (s_row > 0)
-        .then(|| data[s_row - 1][s_col])
-        .flatten()
-        .filter(|p| p.go_through(Direction::North).is_some());
-    let south = (s_row < h - 1)
-        .then(|| data[s_row + 1][s_col])
-        .flatten()
-        .filter(|p| p.go_through(Direction::South).is_some());
-    let west = (s_col > 0)
-        .then(|| data[s_row][s_col - 1])
-        .flatten()
-        .filter(|p| p.go_through(Direction::West).is_some());
-    let east = (s_col < w - 1)
-        .then(|| data[s_row][s_col + 1])
-        .flatten()
-        .filter(|p| p.go_through(Direction::East).is_some());
-
-    let s_pipe = match (
-        north.is_some(),
-        south.is_some(),
-        west.is_some(),
-        east.is_some(),
-    ) {
+    let connects = |direction: Direction| {
+        data.offset(s_loc, direction)
+            .and_then(|position| data[position])
+            .is_some_and(|pipe| pipe.go_through(direction).is_some())
+    };
+    let north = connects(Direction::North);
+    let south = connects(Direction::South);
+    let west = connects(Direction::West);
+    let east = connects(Direction::East);
+
+    let s_pipe = match (north, south, west, east) {
         (true, true, false, false) => Pipe::Vertical,
         (true, false, true, false) => Pipe::NorthWest,
         (true, false, false, true) => Pipe::NorthEast,
@@ -155,25 +109,56 @@ fn parse_etc(input: &str) -> (Vec<Box<[Option<Pipe>]>>, usize) {
         (false, false, true, true) => Pipe::Horizontal,
         adj => unreachable!("invalid S adjacency: {adj:?}"),
     };
-    data[s_row][s_col] = Some(s_pipe);
+    data[s_loc] = Some(s_pipe);
 
     (data, max_distance)
 }
 
-fn part_1(&(_, max_distance): &(Vec<Box<[Option<Pipe>]>>, usize)) -> usize {
+fn part_1(&(_, max_distance): &(Grid<Option<Pipe>>, usize)) -> usize {
     max_distance
 }
 
-fn part_2((map, _): &(Vec<Box<[Option<Pipe>]>>, usize)) -> usize {
+/// The loop, as a polygon through the centers of its tiles. `map` must have only the loop's pipes
+/// left on it.
+fn loop_polygon(map: &Grid<Option<Pipe>>) -> Polygon<i64> {
+    let vertex = |(y, x): (usize, usize)| Point2::new(y as i64, x as i64);
+    let (start, pipe) = map
+        .iter()
+        .find_map(|(position, pipe)| Some((position, (*pipe)?)))
+        .unwrap();
+    let mut direction = Direction::ALL
+        .into_iter()
+        .find_map(|incoming| pipe.go_through(incoming))
+        .unwrap();
+    let mut position = start;
+    let mut vertices = vec![vertex(start)];
+    loop {
+        position = map.offset(position, direction).unwrap();
+        if position == start {
+            break;
+        }
+        vertices.push(vertex(position));
+        direction = map[position].unwrap().go_through(direction).unwrap();
+    }
+    Polygon::new(vertices)
+}
+
+/// The tiles enclosed by the loop are the lattice points inside its polygon.
+fn part_2((map, _): &(Grid<Option<Pipe>>, usize)) -> i64 {
+    loop_polygon(map).interior_points()
+}
+
+/// Like [`part_2`], but by scanning each row and counting how many times it crosses the loop.
+fn part_2_scanline((map, _): &(Grid<Option<Pipe>>, usize)) -> usize {
     let mut inner_cells = 0;
 
-    for row in map {
+    for row in map.rows() {
         let mut crosses = 0_usize;
         let mut partial_cross_src_was_north = false;
         for cell in row.iter().copied() {
             match cell {
                 None => {
-                    if crosses % 2 != 0 {
+                    if !crosses.is_multiple_of(2) {
                         inner_cells += 1;
                     }
                 }
@@ -199,46 +184,25 @@ fn part_2((map, _): &(Vec<Box<[Option<Pipe>]>>, usize)) -> usize {
     inner_cells
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    let session = session.trim();
-    let mut both = PreParsed::new(parse_etc, part_1, part_2);
-    let part_2 = both.part_2();
-    if let Err(error) = aoc_magic!(session, 2023:10:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    let part_1 = both.part_1();
-    if let Err(error) = aoc_magic!(session, 2023:10:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub const ALTERNATIVES: &[Alternative] = &[Alternative {
+    part: 2,
+    name: "scanline",
+    run: |input, _| Ok(part_2_scanline(&parse_etc(input)).to_string()),
+}];
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(PreParsed::new(parse_etc, part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "-L|F7
-7S-7|
-L|7||
--L-J|
-L|-JF";
-    assert_eq!(part_1(&parse_etc(input)), 4);
-
-    let input = "..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...";
-    assert_eq!(part_1(&parse_etc(input)), 8);
-
-    let input = ".F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ...";
-    assert_eq!(part_2(&parse_etc(input)), 8);
+    crate::examples::check(10);
+}
+
+#[test]
+fn scanline_agrees() {
+    for example in crate::examples::load(10).unwrap() {
+        let parsed = parse_etc(&example.input);
+        assert_eq!(part_2(&parsed), part_2_scanline(&parsed) as i64, "{}", example.name);
+    }
 }
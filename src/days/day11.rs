@@ -0,0 +1,99 @@
+use crate::*;
+use itertools::Itertools;
+
+struct Data {
+    galaxy_locations: Vec<(usize, usize)>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+fn parse(input: &str) -> Data {
+    let data = Grid::<u8>::parse(input);
+    let empty_rows = (0..data.height())
+        .filter(|&row| data.row(row).iter().all(|&cell| cell == b'.'))
+        .collect_vec();
+    let empty_cols = (0..data.width())
+        .filter(|&col| data.column(col).all(|&cell| cell == b'.'))
+        .collect_vec();
+    let galaxy_locations = data
+        .iter()
+        .filter_map(|(position, &cell)| (cell == b'#').then_some(position))
+        .collect_vec();
+    Data {
+        galaxy_locations,
+        empty_rows,
+        empty_cols,
+    }
+}
+
+fn count_between(start: usize, end: usize, sorted_values: &[usize]) -> usize {
+    let start_idx = sorted_values.partition_point(|&value| value <= start);
+    let end_idx = sorted_values.partition_point(|&value| value < end);
+    end_idx - start_idx
+}
+
+fn distance(
+    (y1, x1): (usize, usize),
+    (y2, x2): (usize, usize),
+    empty_rows: &[usize],
+    empty_cols: &[usize],
+    empty_space_multiplier: usize,
+) -> usize {
+    let (y1, y2) = (Ord::min(y1, y2), Ord::max(y1, y2));
+    let (x1, x2) = (Ord::min(x1, x2), Ord::max(x1, x2));
+    (y2 - y1)
+        + (x2 - x1)
+        + count_between(y1, y2, empty_rows) * (empty_space_multiplier - 1)
+        + count_between(x1, x2, empty_cols) * (empty_space_multiplier - 1)
+}
+
+/// The sum of the distances between every pair of galaxies, where each empty row or column
+/// is `expansion` times as wide.
+fn total_distance(input: &Data, expansion: usize) -> usize {
+    let Data {
+        galaxy_locations,
+        empty_rows,
+        empty_cols,
+    } = input;
+    let mut acc = 0;
+    for i in 0..galaxy_locations.len() {
+        for j in 0..i {
+            acc += distance(
+                galaxy_locations[i],
+                galaxy_locations[j],
+                empty_rows,
+                empty_cols,
+                expansion,
+            );
+        }
+    }
+    acc
+}
+
+/// The `expansion` parameter overrides how many times as wide empty rows and columns are,
+/// for both parts.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let expansion = params.get("expansion")?;
+    if expansion == Some(0) {
+        return Err(Error::new(
+            "the expansion must be at least 1, as empty rows and columns cannot disappear",
+        ));
+    }
+    let part_1 = move |input: &Data| total_distance(input, expansion.unwrap_or(2));
+    let part_2 = move |input: &Data| total_distance(input, expansion.unwrap_or(1_000_000));
+    Ok(Box::new(PreParsed::new(parse, part_1, part_2)))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(11);
+}
+
+#[test]
+fn zero_expansion() {
+    let error = solution(&Params::new().with("expansion", 0)).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "the expansion must be at least 1, as empty rows and columns cannot disappear"
+    );
+}
@@ -0,0 +1,151 @@
+use crate::{nonogram::LineSolver, *};
+use itertools::Itertools;
+use num_bigint::BigUint;
+#[cfg(test)]
+use rand::Rng;
+
+struct Row {
+    known_cells: Vec<Option<bool>>,
+    expected_chunks: Vec<usize>,
+}
+
+fn parse(input: &str) -> Vec<Row> {
+    input
+        .lines()
+        .map(str::trim)
+        .map(|line| {
+            let (known_cells, expected_chunks) = line.split_once(' ').unwrap();
+            let known_cells: Vec<Option<bool>> = known_cells
+                .chars()
+                .map(|c| match c {
+                    '.' => Some(false),
+                    '?' => None,
+                    '#' => Some(true),
+                    _ => unreachable!(),
+                })
+                .collect();
+            let expected_chunks = expected_chunks
+                .split(',')
+                .map(|field| field.parse::<usize>().unwrap())
+                .collect_vec();
+            Row {
+                known_cells,
+                expected_chunks,
+            }
+        })
+        .collect()
+}
+
+fn part_1(input: &Vec<Row>) -> usize {
+    input
+        .iter()
+        .map(|row| LineSolver::<usize>::new(&row.known_cells, &row.expected_chunks).count())
+        .sum()
+}
+
+fn part_2(input: &Vec<Row>, times: usize) -> BigUint {
+    input
+        .iter()
+        .map(|row| {
+            let (known_cells, expected_chunks) =
+                nonogram::unfold(&row.known_cells, &row.expected_chunks, times);
+            LineSolver::<BigUint>::new(&known_cells, &expected_chunks).count()
+        })
+        .sum()
+}
+
+/// A random row of up to `max_len` springs, some of them damaged, with some conditions forgotten.
+/// There is always at least one damaged spring, as in the real puzzle.
+#[cfg(test)]
+fn random_row(rng: &mut impl Rng, max_len: usize) -> String {
+    let len = rng.gen_range(1..=max_len);
+    let mut springs = (0..len).map(|_| rng.gen_bool(0.5)).collect_vec();
+    springs[rng.gen_range(0..len)] = true;
+    let chunks = springs
+        .split(|&damaged| !damaged)
+        .map(<[bool]>::len)
+        .filter(|&len| len > 0)
+        .join(",");
+    let forget = rng.gen_range(0.0..=1.0);
+    let cells: String = springs
+        .iter()
+        .map(|&damaged| match (rng.gen_bool(forget), damaged) {
+            (true, _) => '?',
+            (false, true) => '#',
+            (false, false) => '.',
+        })
+        .collect();
+    format!("{cells} {chunks}")
+}
+
+/// A random puzzle input, with rows short enough to count by brute force when unfolded twice.
+#[cfg(test)]
+fn random_input(rng: &mut impl Rng) -> String {
+    (0..rng.gen_range(1..=5))
+        .map(|_| random_row(rng, 8))
+        .map(|row| row + "\n")
+        .collect()
+}
+
+/// The `unfold` parameter overrides how many copies of each row part 2 unfolds into.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let times = params.get("unfold")?.unwrap_or(5);
+    Ok(Box::new(PreParsed::new(parse, part_1, move |input: &Vec<Row>| {
+        part_2(input, times)
+    })))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(12);
+}
+
+/// Both parts, unfolding twice for part 2, by trying every way to fill in the unknown springs.
+#[cfg(test)]
+fn brute_force(input: &str) -> (usize, BigUint) {
+    let count = |row: &Row| {
+        let unknown = row.known_cells.iter().filter(|cell| cell.is_none()).count();
+        (0..1_u32 << unknown)
+            .filter(|guesses| {
+                let mut guess = 0;
+                let springs = row
+                    .known_cells
+                    .iter()
+                    .map(|cell| {
+                        cell.unwrap_or_else(|| {
+                            guess += 1;
+                            guesses & (1 << (guess - 1)) != 0
+                        })
+                    })
+                    .collect_vec();
+                springs
+                    .split(|&damaged| !damaged)
+                    .map(<[bool]>::len)
+                    .filter(|&len| len > 0)
+                    .eq(row.expected_chunks.iter().copied())
+            })
+            .count()
+    };
+    let rows = parse(input);
+    let part_1 = rows.iter().map(count).sum();
+    let part_2 = rows
+        .iter()
+        .map(|row| {
+            let (known_cells, expected_chunks) =
+                nonogram::unfold(&row.known_cells, &row.expected_chunks, 2);
+            BigUint::from(count(&Row {
+                known_cells,
+                expected_chunks,
+            }))
+        })
+        .sum();
+    (part_1, part_2)
+}
+
+#[test]
+fn fuzz() {
+    crate::fuzz::check(200, random_input, brute_force, |input| {
+        let rows = parse(input);
+        (part_1(&rows), part_2(&rows, 2))
+    });
+}
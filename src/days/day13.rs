@@ -0,0 +1,107 @@
+use crate::*;
+use itertools::Itertools;
+
+fn eq_rev<T: Eq>(s1: impl Iterator<Item = T>, s2: impl DoubleEndedIterator<Item = T>) -> bool {
+    s1.eq(s2.rev())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SplitLines {
+    v_splits: Vec<usize>,
+    h_splits: Vec<usize>,
+}
+
+impl SplitLines {
+    fn summarize(self) -> Option<usize> {
+        match (&self.v_splits[..], &self.h_splits[..]) {
+            ([], &[y]) => Some(100 * y),
+            (&[x], []) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn but_not(mut self, part1: &Self) -> Self {
+        self.h_splits
+            .retain(|h_split| !part1.h_splits.contains(h_split));
+        self.v_splits
+            .retain(|v_split| !part1.v_splits.contains(v_split));
+        self
+    }
+}
+
+fn v_split(data: &Grid<u8>) -> Vec<usize> {
+    let w = data.width();
+    (1..w)
+        .filter(|&v_split| {
+            let len = std::cmp::min(v_split, w - v_split);
+            data.rows().all(|row| {
+                eq_rev(
+                    row[v_split - len..v_split].iter(),
+                    row[v_split..v_split + len].iter(),
+                )
+            })
+        })
+        .collect_vec()
+}
+
+fn h_split(data: &Grid<u8>) -> Vec<usize> {
+    v_split(&data.transpose())
+}
+
+fn solve(data: &Grid<u8>) -> SplitLines {
+    SplitLines {
+        v_splits: v_split(data),
+        h_splits: h_split(data),
+    }
+}
+
+fn parse(input: &str) -> Vec<Grid<u8>> {
+    groups(input)
+        .iter()
+        .map(|pattern| Grid::from_lines(pattern))
+        .collect_vec()
+}
+
+fn part_1(input: &str) -> usize {
+    parse(input)
+        .iter()
+        .map(|pattern| solve(pattern).summarize().unwrap())
+        .sum()
+}
+
+fn part_2(input: &str) -> usize {
+    parse(input)
+        .into_iter()
+        .map(|mut pattern| {
+            let part1 = solve(&pattern);
+
+            let invert = |n: u8| -> u8 {
+                match n {
+                    b'#' => b'.',
+                    _ => b'#',
+                }
+            };
+
+            for position in pattern.positions() {
+                pattern[position] = invert(pattern[position]);
+                let splits = solve(&pattern);
+                if splits != part1 {
+                    if let Some(val) = splits.but_not(&part1).summarize() {
+                        return val;
+                    }
+                }
+                pattern[position] = invert(pattern[position]);
+            }
+            unreachable!()
+        })
+        .sum()
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(13);
+}
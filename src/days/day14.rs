@@ -0,0 +1,241 @@
+use crate::*;
+use itertools::Itertools;
+#[cfg(test)]
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Space {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl GridCell for Space {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'O' => Some(Space::RoundRock),
+            b'.' => Some(Space::Empty),
+            b'#' => Some(Space::CubeRock),
+            _ => None,
+        }
+    }
+
+    fn to_byte(&self) -> u8 {
+        match self {
+            Space::RoundRock => b'O',
+            Space::CubeRock => b'#',
+            Space::Empty => b'.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Platform {
+    cells: Grid<Space>,
+}
+
+impl Platform {
+    fn tilt_north(&mut self) {
+        for x in 0..self.cells.width() {
+            let mut column = self.cells.column_mut(x);
+            tilt(&mut column);
+        }
+    }
+    fn tilt_south(&mut self) {
+        for x in 0..self.cells.width() {
+            let mut column = self.cells.column_mut(x);
+            column.reverse();
+            tilt(&mut column);
+        }
+    }
+    fn tilt_east(&mut self) {
+        for y in 0..self.cells.height() {
+            let mut row = self.cells.row_mut(y).iter_mut().rev().collect_vec();
+            tilt(&mut row);
+        }
+    }
+    fn tilt_west(&mut self) {
+        for y in 0..self.cells.height() {
+            let mut row = self.cells.row_mut(y).iter_mut().collect_vec();
+            tilt(&mut row);
+        }
+    }
+
+    fn calculate_load(&self) -> usize {
+        let h = self.cells.height();
+        self.cells
+            .iter()
+            .map(|((y, _), &cell)| if cell == Space::RoundRock { h - y } else { 0 })
+            .sum()
+    }
+}
+
+fn tilt(column: &mut [&mut Space]) {
+    let mut read_head = 0;
+    let mut write_head = 0;
+    while read_head < column.len() {
+        match column[read_head] {
+            Space::RoundRock => {
+                if read_head != write_head {
+                    *column[write_head] = Space::RoundRock;
+                    *column[read_head] = Space::Empty;
+                }
+                read_head += 1;
+                write_head += 1;
+            }
+            Space::Empty => {
+                read_head += 1;
+            }
+            Space::CubeRock => {
+                read_head += 1;
+                write_head = read_head;
+            }
+        }
+    }
+}
+
+fn tilt_column_north(column: &mut [u8]) {
+    let mut read_head = 0;
+    let mut write_head = 0;
+    while read_head < column.len() {
+        match column[read_head] {
+            b'O' => {
+                if read_head != write_head {
+                    column[write_head] = b'O';
+                    column[read_head] = b'.';
+                }
+                read_head += 1;
+                write_head += 1;
+            }
+            b'.' => {
+                read_head += 1;
+            }
+            b'#' => {
+                read_head += 1;
+                write_head = read_head;
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn calculate_column_load(column: &[u8]) -> usize {
+    let n = column.len();
+    column
+        .iter()
+        .enumerate()
+        .map(|(y, &rock)| if rock == b'O' { n - y } else { 0 })
+        .sum()
+}
+
+fn part_1(input: &str) -> usize {
+    let data = Grid::<u8>::parse(input);
+    data.columns()
+        .map(|column| {
+            let mut column = column.copied().collect_vec();
+            tilt_column_north(&mut column);
+            calculate_column_load(&column)
+        })
+        .sum()
+}
+
+fn part_2(input: &str, cycles: usize) -> usize {
+    let platform = Platform {
+        cells: Grid::parse(input),
+    };
+    let spin = |platform: &Platform| {
+        let mut platform = platform.clone();
+        platform.tilt_north();
+        platform.tilt_west();
+        platform.tilt_south();
+        platform.tilt_east();
+        platform
+    };
+    let (_, platform) = cycle::fast_forward_hashed(platform, spin, cycles);
+    platform.calculate_load()
+}
+
+/// A random platform of up to 8 by 8 spaces.
+#[cfg(test)]
+fn random_input(rng: &mut impl Rng) -> String {
+    let (h, w) = (rng.gen_range(1..=8), rng.gen_range(1..=8));
+    let rocks = rng.gen_range(0.0..=1.0);
+    let cubes = rng.gen_range(0.0..=0.5);
+    (0..h)
+        .map(|_| {
+            (0..w)
+                .map(|_| match rng.gen_range(0.0..1.0) {
+                    x if x < cubes => '#',
+                    x if x < cubes + rocks * (1.0 - cubes) => 'O',
+                    _ => '.',
+                })
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// The `cycles` parameter overrides how many spin cycles part 2 runs.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let cycles = params.get("cycles")?.unwrap_or(1_000_000_000);
+    Ok(Box::new(SeparateFunctions::new(part_1, move |input: &str| {
+        part_2(input, cycles)
+    })))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(14);
+}
+
+/// Both parts, running `cycles` spin cycles for part 2, by rolling rocks one space at a time and
+/// running every cycle.
+#[cfg(test)]
+fn brute_force(input: &str, cycles: usize) -> (usize, usize) {
+    let mut map = Grid::<u8>::parse(input);
+    let roll = |map: &mut Grid<u8>, direction: Direction| loop {
+        let mut moved = false;
+        for position in map.positions().collect_vec() {
+            if let Some(next) = map.offset(position, direction) {
+                if map[position] == b'O' && map[next] == b'.' {
+                    (map[position], map[next]) = (b'.', b'O');
+                    moved = true;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+    };
+    let load = |map: &Grid<u8>| {
+        map.positions()
+            .filter(|&position| map[position] == b'O')
+            .map(|(y, _)| map.height() - y)
+            .sum()
+    };
+    let mut tilted = map.clone();
+    roll(&mut tilted, Direction::North);
+    for _ in 0..cycles {
+        for direction in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            roll(&mut map, direction);
+        }
+    }
+    (load(&tilted), load(&map))
+}
+
+#[test]
+fn fuzz() {
+    for cycles in [0, 1, 2, 3, 10, 37] {
+        crate::fuzz::check(
+            40,
+            random_input,
+            |input| brute_force(input, cycles),
+            |input| (part_1(input), part_2(input, cycles)),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_driver::*;
+use crate::{SeparateFunctions, Solution};
 use indexmap::IndexMap;
 use itertools::Itertools;
 
@@ -12,7 +12,7 @@ fn hash(s: &str) -> usize {
 }
 
 fn part_1(input: &str) -> usize {
-    input.split(',').map(hash).sum()
+    input.trim_end().split(',').map(hash).sum()
 }
 
 /// None is remove
@@ -25,7 +25,7 @@ fn parse_lens(s: &str) -> (&str, Option<usize>) {
 }
 
 fn part_2(input: &str) -> usize {
-    let values = input.split(',').collect_vec();
+    let values = input.trim_end().split(',').collect_vec();
     let mut boxes: Vec<IndexMap<&str, usize>> = vec![IndexMap::new(); 256];
     for &value in &values {
         let (label, focal_length) = parse_lens(value);
@@ -51,22 +51,11 @@ fn part_2(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, 2023:15:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    if let Err(error) = aoc_magic!(session, 2023:15:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "HASH";
-    assert_eq!(part_1(input), 52);
-    let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-    assert_eq!(part_1(input), 1320);
-    assert_eq!(part_2(input), 145);
+    crate::examples::check(15);
 }
@@ -0,0 +1,170 @@
+use std::collections::{BTreeSet, HashMap};
+
+#[cfg(test)]
+use rand::{seq::SliceRandom, Rng};
+
+use crate::*;
+
+fn step_beams(
+    map: &Grid<u8>,
+    beams: Vec<(usize, usize, Direction)>,
+    seen: &mut HashMap<(usize, usize), BTreeSet<Direction>>,
+) -> Vec<(usize, usize, Direction)> {
+    let mut new_beams = Vec::with_capacity(beams.len() * 2);
+    for (y, x, dir) in beams {
+        if !seen.entry((y, x)).or_default().insert(dir) {
+            // already seen
+            continue;
+        }
+        let go = |new_beams: &mut Vec<_>, direction: Direction| {
+            if let Some((y, x)) = map.offset((y, x), direction) {
+                new_beams.push((y, x, direction));
+            }
+        };
+        match (map[(y, x)], dir) {
+            (0_u8..=44_u8, _)
+            | (48_u8..=91_u8, _)
+            | (93_u8..=123_u8, _)
+            | (125_u8..=u8::MAX, _) => unreachable!(),
+            // pass-throughs
+            (b'.' | b'-', Direction::East) => go(&mut new_beams, Direction::East),
+            (b'.' | b'-', Direction::West) => go(&mut new_beams, Direction::West),
+            (b'.' | b'|', Direction::South) => go(&mut new_beams, Direction::South),
+            (b'.' | b'|', Direction::North) => go(&mut new_beams, Direction::North),
+            // mirrors EW
+            (b'/', Direction::East) | (b'\\', Direction::West) => {
+                go(&mut new_beams, Direction::North)
+            }
+            (b'/', Direction::West) | (b'\\', Direction::East) => {
+                go(&mut new_beams, Direction::South)
+            }
+            // mirrors NS
+            (b'/', Direction::North) | (b'\\', Direction::South) => {
+                go(&mut new_beams, Direction::East)
+            }
+            (b'/', Direction::South) | (b'\\', Direction::North) => {
+                go(&mut new_beams, Direction::West)
+            }
+            // splits
+            (b'|', Direction::East | Direction::West) => {
+                go(&mut new_beams, Direction::North);
+                go(&mut new_beams, Direction::South);
+            }
+            (b'-', Direction::North | Direction::South) => {
+                go(&mut new_beams, Direction::East);
+                go(&mut new_beams, Direction::West);
+            }
+        }
+    }
+    new_beams
+}
+
+fn reachable_starting_at(map: &Grid<u8>, y: usize, x: usize, dir: Direction) -> usize {
+    let mut seen: HashMap<(usize, usize), BTreeSet<Direction>> = HashMap::new();
+    let mut beams = vec![(y, x, dir)];
+
+    while !beams.is_empty() {
+        beams = step_beams(map, beams, &mut seen);
+    }
+
+    seen.len()
+}
+
+fn solve(input: &str) -> (usize, usize) {
+    let map = Grid::<u8>::parse(input);
+    let (h, w) = map.size();
+    let mut easts = (0..h)
+        .map(|y| reachable_starting_at(&map, y, 0, Direction::East))
+        .peekable();
+    let wests = (0..h).map(|y| reachable_starting_at(&map, y, w - 1, Direction::West));
+    let souths = (0..w).map(|x| reachable_starting_at(&map, 0, x, Direction::South));
+    let norths = (0..w).map(|x| reachable_starting_at(&map, h - 1, x, Direction::North));
+
+    let part_1 = *easts.peek().unwrap();
+    let part_2 = easts
+        .chain(wests)
+        .chain(souths)
+        .chain(norths)
+        .max()
+        .unwrap();
+    (part_1, part_2)
+}
+
+/// A random contraption of up to 8 by 8 tiles.
+#[cfg(test)]
+fn random_input(rng: &mut impl Rng) -> String {
+    let (h, w) = (rng.gen_range(1..=8), rng.gen_range(1..=8));
+    let empty = rng.gen_range(0.0..=1.0);
+    (0..h)
+        .map(|_| {
+            (0..w)
+                .map(|_| {
+                    if rng.gen_bool(empty) {
+                        '.'
+                    } else {
+                        *['/', '\\', '|', '-'].choose(rng).unwrap()
+                    }
+                })
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SingleFunction::new(solve))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(16);
+}
+
+/// Both parts, by following the beam from each edge tile one step at a time.
+#[cfg(test)]
+fn brute_force(input: &str) -> (usize, usize) {
+    use std::collections::HashSet;
+
+    let map = Grid::<u8>::parse(input);
+    let (h, w) = map.size();
+    let energized = |start: (usize, usize), direction: Direction| {
+        let mut seen = HashSet::new();
+        let mut beams = vec![(start, direction)];
+        while let Some((position, direction)) = beams.pop() {
+            if !seen.insert((position, direction)) {
+                continue;
+            }
+            let turns = match (map[position], direction) {
+                (b'/', Direction::East) | (b'\\', Direction::West) => vec![Direction::North],
+                (b'/', Direction::West) | (b'\\', Direction::East) => vec![Direction::South],
+                (b'/', Direction::North) | (b'\\', Direction::South) => vec![Direction::East],
+                (b'/', Direction::South) | (b'\\', Direction::North) => vec![Direction::West],
+                (b'|', Direction::East | Direction::West) => {
+                    vec![Direction::North, Direction::South]
+                }
+                (b'-', Direction::North | Direction::South) => {
+                    vec![Direction::East, Direction::West]
+                }
+                _ => vec![direction],
+            };
+            beams.extend(turns.into_iter().filter_map(|direction| {
+                map.offset(position, direction)
+                    .map(|next| (next, direction))
+            }));
+        }
+        seen.into_iter().map(|(position, _)| position).unique().count()
+    };
+    let starts = (0..h)
+        .flat_map(|y| [((y, 0), Direction::East), ((y, w - 1), Direction::West)])
+        .chain((0..w).flat_map(|x| [((0, x), Direction::South), ((h - 1, x), Direction::North)]));
+    let part_2 = starts
+        .map(|(start, direction)| energized(start, direction))
+        .max()
+        .unwrap();
+    (energized((0, 0), Direction::East), part_2)
+}
+
+#[test]
+fn fuzz() {
+    crate::fuzz::check(200, random_input, brute_force, solve);
+}
@@ -0,0 +1,123 @@
+use crate::*;
+
+fn parse(input: &str) -> Result<Grid<u8>, Error> {
+    Grid::try_parse_with(input, |b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err(Error::new(format!("unknown block {:?}", b as char))),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: (u8, u8),
+    last_move_direction: Direction,
+    last_move_count: u8,
+}
+
+impl State {
+    const START: Self = State {
+        position: (0, 0),
+        last_move_direction: Direction::East,
+        last_move_count: 0,
+    };
+
+    /// Returns the new state and the heat loss incurred by the move.
+    fn and_move(mut self, map: &Grid<u8>, direction: Direction) -> Option<(Self, usize)> {
+        if self.last_move_direction == direction.opposite()
+            || (self.last_move_direction == direction && self.last_move_count >= 3)
+        {
+            return None;
+        }
+        let (y, x) = self.position;
+        let (y, x) = map.offset((y.into(), x.into()), direction)?;
+        if self.last_move_direction == direction {
+            self.last_move_count += 1;
+        } else {
+            self.last_move_direction = direction;
+            self.last_move_count = 1;
+        }
+        self.position = (y as u8, x as u8);
+        Some((self, map[(y, x)] as usize))
+    }
+
+    /// Returns the new state and the heat loss incurred by the move.
+    fn and_ultra_move(mut self, map: &Grid<u8>, direction: Direction) -> Option<(Self, usize)> {
+        if self.last_move_direction == direction.opposite()
+            || (self.last_move_direction == direction && self.last_move_count >= 10)
+            || (self.last_move_direction != direction && self.last_move_count < 4)
+        {
+            return None;
+        }
+        let (y, x) = self.position;
+        let (y, x) = map.offset((y.into(), x.into()), direction)?;
+        if self.last_move_direction == direction {
+            self.last_move_count += 1;
+        } else {
+            self.last_move_direction = direction;
+            self.last_move_count = 1;
+        }
+        self.position = (y as u8, x as u8);
+        Some((self, map[(y, x)] as usize))
+    }
+
+    /// Lower bound on the remaining heat loss, since every block loses at least 1.
+    fn distance_to_goal(&self, map: &Grid<u8>) -> usize {
+        let (h, w) = map.size();
+        let (y, x) = self.position;
+        (h - 1 - y as usize) + (w - 1 - x as usize)
+    }
+}
+
+fn part_1(map: &Grid<u8>) -> Result<usize, Error> {
+    let (h, w) = map.size();
+    let goal = ((h - 1) as u8, (w - 1) as u8);
+
+    let path = astar(
+        State::START,
+        |&state| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| state.and_move(map, direction))
+        },
+        |state| state.position == goal,
+        |state| state.distance_to_goal(map),
+    )
+    .ok_or_else(|| Error::new("the goal is unreachable"))?;
+    Ok(path.cost)
+}
+
+fn part_2(map: &Grid<u8>) -> Result<usize, Error> {
+    let (h, w) = map.size();
+    let goal = ((h - 1) as u8, (w - 1) as u8);
+
+    let path = astar(
+        State::START,
+        |&state| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| state.and_ultra_move(map, direction))
+        },
+        |state| state.position == goal && state.last_move_count >= 4,
+        |state| state.distance_to_goal(map),
+    )
+    .ok_or_else(|| Error::new("the goal is unreachable"))?;
+    Ok(path.cost)
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(17);
+}
+
+#[test]
+fn unreachable() {
+    // Part 2's crucible must move 4 blocks East before it can turn, which a single column never
+    // allows.
+    let map = parse("1\n2\n3\n4\n").unwrap();
+    assert_eq!(part_1(&map), Ok(9));
+    assert_eq!(part_2(&map), Err(Error::new("the goal is unreachable")));
+}
@@ -0,0 +1,46 @@
+use crate::{Direction, Polygon, SingleFunction, Solution};
+
+/// How many cubic meters the lagoon holds: the trench dug along the path, and everything inside.
+fn calculate_area(path: &[(Direction, i64)]) -> i64 {
+    let lagoon = Polygon::from_steps(path.iter().copied());
+    lagoon.boundary_points() + lagoon.interior_points()
+}
+
+fn solve(input: &str) -> (i64, i64) {
+    let (path1, path2): (Vec<_>, Vec<_>) = input
+        .lines()
+        .map(str::trim)
+        .map(|line| {
+            let mut fields = line.split_whitespace();
+            let direction1 = match fields.next().unwrap() {
+                "R" => Direction::East,
+                "U" => Direction::North,
+                "D" => Direction::South,
+                "L" => Direction::West,
+                _ => unreachable!(),
+            };
+            let count1 = fields.next().unwrap().parse::<i64>().unwrap();
+            let rgb = fields.next().unwrap();
+            let count2 = i64::from_str_radix(&rgb[2..rgb.len() - 2], 16).unwrap();
+            let direction2 = match &rgb[rgb.len() - 2..][..1] {
+                "0" => Direction::East,
+                "3" => Direction::North,
+                "1" => Direction::South,
+                "2" => Direction::West,
+                _ => unreachable!(),
+            };
+
+            ((direction1, count1), (direction2, count2))
+        })
+        .unzip();
+    (calculate_area(&path1), calculate_area(&path2))
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SingleFunction::new(solve))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(18);
+}
@@ -1,7 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
-use aoc_driver::*;
+use crate::{SeparateFunctions, Solution};
 use itertools::Itertools;
+#[cfg(test)]
+use rand::{seq::SliceRandom, Rng};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Part {
@@ -348,36 +350,140 @@ fn part_2(input: &str) -> usize {
     total_number_of_accepted
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, 2023:19:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
+/// A random system of up to 8 workflows and a few parts. Workflows only send parts to later
+/// workflows, so every part is eventually accepted or rejected.
+#[cfg(test)]
+fn random_input(rng: &mut impl Rng) -> String {
+    let count = rng.gen_range(1..=8);
+    let mut names = vec!["in".to_string()];
+    while names.len() < count {
+        let name: String = (0..rng.gen_range(1..=3))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    fn destination(rng: &mut impl Rng, later: &[String]) -> String {
+        match rng.gen_range(0..later.len() + 2) {
+            0 => "A".to_string(),
+            1 => "R".to_string(),
+            i => later[i - 2].clone(),
+        }
     }
-    if let Err(error) = aoc_magic!(session, 2023:19:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
+    let mut input = String::new();
+    for (from, name) in names.iter().enumerate() {
+        let later = &names[from + 1..];
+        let mut rules = (0..rng.gen_range(0..=3))
+            .map(|_| {
+                let rating = *['x', 'm', 'a', 's'].choose(rng).unwrap();
+                let comparison = *['<', '>'].choose(rng).unwrap();
+                let value = rng.gen_range(1..=4000);
+                format!("{rating}{comparison}{value}:{}", destination(rng, later))
+            })
+            .collect_vec();
+        rules.push(destination(rng, later));
+        let rules = rules.join(",");
+        input += &format!("{name}{{{rules}}}\n");
+    }
+    input.push('\n');
+    for _ in 0..rng.gen_range(1..=5) {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        input += &format!("{{x={x},m={m},a={a},s={s}}}\n");
     }
+    input
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}";
-    assert_eq!(part_1(input), 19114);
-    assert_eq!(part_2(input), 167409079868000);
+    crate::examples::check(19);
+}
+
+/// Both parts, by running parts through the workflows one at a time. Part 2 only needs to run one
+/// part from each box of ratings that no rule tells apart.
+#[cfg(test)]
+fn brute_force(input: &str) -> (usize, usize) {
+    type Rule<'a> = (Option<(usize, u8, usize)>, &'a str);
+
+    let (workflows, parts) = input.split_once("\n\n").unwrap();
+    let workflows: HashMap<&str, Vec<Rule>> = workflows
+        .lines()
+        .map(|line| {
+            let (name, rules) = line.strip_suffix('}').unwrap().split_once('{').unwrap();
+            let rules = rules
+                .split(',')
+                .map(|rule| match rule.split_once(':') {
+                    Some((condition, destination)) => {
+                        let rating = "xmas".find(&condition[..1]).unwrap();
+                        let value = condition[2..].parse().unwrap();
+                        (Some((rating, condition.as_bytes()[1], value)), destination)
+                    }
+                    None => (None, rule),
+                })
+                .collect();
+            (name, rules)
+        })
+        .collect();
+    let accepted = |part: [usize; 4]| {
+        let mut at = "in";
+        while let Some(rules) = workflows.get(at) {
+            at = rules
+                .iter()
+                .find(|(condition, _)| match *condition {
+                    None => true,
+                    Some((rating, b'<', value)) => part[rating] < value,
+                    Some((rating, _, value)) => part[rating] > value,
+                })
+                .unwrap()
+                .1;
+        }
+        at == "A"
+    };
+
+    let part_1 = parts
+        .lines()
+        .map(|line| {
+            let ratings = line.trim_matches(['{', '}']).split(',');
+            let ratings = ratings.map(|rating| rating[2..].parse().unwrap());
+            <[usize; 4]>::try_from(ratings.collect_vec()).unwrap()
+        })
+        .filter(|&part| accepted(part))
+        .map(|part| part.iter().sum::<usize>())
+        .sum();
+
+    // The first rating of each box along each axis.
+    let mut starts = vec![vec![1, 4001]; 4];
+    for (condition, _) in workflows.values().flatten() {
+        match *condition {
+            Some((rating, b'<', value)) => starts[rating].push(value),
+            Some((rating, _, value)) => starts[rating].push(value + 1),
+            None => {}
+        }
+    }
+    let boxes = starts
+        .into_iter()
+        .map(|starts| {
+            let starts = starts.into_iter().sorted().dedup().collect_vec();
+            starts
+                .windows(2)
+                .map(|window| (window[0], window[1] - window[0]))
+                .collect_vec()
+        })
+        .multi_cartesian_product();
+    let part_2 = boxes
+        .filter(|ratings| accepted([0, 1, 2, 3].map(|rating| ratings[rating].0)))
+        .map(|ratings| ratings.iter().map(|&(_, count)| count).product::<usize>())
+        .sum();
+    (part_1, part_2)
+}
+
+#[test]
+fn fuzz() {
+    crate::fuzz::check(200, random_input, brute_force, |input| {
+        (part_1(input), part_2(input))
+    });
 }
@@ -0,0 +1,109 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy)]
+struct CubeSet {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Game {
+    idx: usize,
+    sets: Vec<CubeSet>,
+}
+
+impl Game {
+    fn minimum_possible(&self) -> (usize, usize, usize) {
+        let [mut red, mut green, mut blue] = [0; 3];
+        for set in &self.sets {
+            red = red.max(set.red);
+            green = green.max(set.green);
+            blue = blue.max(set.blue);
+        }
+        (red, green, blue)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, Error> {
+    try_parse_lines_located(input, |line| {
+        let (game_n, rest) = line
+            .split_once(": ")
+            .ok_or_else(|| Error::new("expected \"Game N: ...\"").at(line, line))?;
+        let idx: usize = parse_field(
+            game_n
+                .strip_prefix("Game ")
+                .ok_or_else(|| Error::new("expected \"Game \"").at(game_n, line))?,
+            line,
+        )?;
+        let sets = rest.split(';').map(|set| {
+            let set = set.trim();
+            let cubes = set.split(',');
+            let [mut red, mut green, mut blue] = [0; 3];
+            for cube in cubes {
+                let cube = cube.trim();
+                let (count, color) = cube
+                    .split_once(' ')
+                    .ok_or_else(|| Error::new("expected \"N color\"").at(cube, line))?;
+                let count = parse_field(count, line)?;
+                match color {
+                    "red" => red = count,
+                    "green" => green = count,
+                    "blue" => blue = count,
+                    _ => return Err(Error::new(format!("unknown color {color:?}")).at(color, line)),
+                }
+            }
+            Ok(CubeSet { red, green, blue })
+        });
+        Ok(Game {
+            idx,
+            sets: sets.collect::<Result<_, _>>()?,
+        })
+    })
+}
+
+fn part_1(games: &Vec<Game>) -> usize {
+    let red_total = 12;
+    let green_total = 13;
+    let blue_total = 14;
+    games
+        .iter()
+        .filter_map(|game| {
+            game.sets
+                .iter()
+                .all(|set| {
+                    set.red <= red_total && set.green <= green_total && set.blue <= blue_total
+                })
+                .then_some(game.idx)
+        })
+        .sum()
+}
+fn part_2(games: &Vec<Game>) -> usize {
+    games
+        .iter()
+        .map(|game| {
+            let (r, g, b) = game.minimum_possible();
+            r * g * b
+        })
+        .sum()
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(2);
+}
+
+#[test]
+fn parse_error() {
+    let mut both = TryPreParsed::new(parse, part_1, part_2);
+    let error = both.try_part_1()("Game 1: 3 blue\nGame 2: 4 red, x green").unwrap_err();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(16)));
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 16: parser failed: invalid value \"x\": invalid digit found in string"
+    );
+}
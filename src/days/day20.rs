@@ -0,0 +1,39 @@
+use crate::*;
+
+fn part_1(circuit: &Circuit) -> usize {
+    let mut simulator = circuit.simulator();
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        simulator.press();
+        for pulse in &mut simulator {
+            if pulse.high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        }
+    }
+    low * high
+}
+
+fn part_2(circuit: &Circuit) -> Result<usize, Error> {
+    // rx gets a low pulse when every counter feeding its conjunction fires on the same press.
+    Ok(circuit.analyze("rx", 1 << 16)?.presses())
+}
+
+pub fn graphs(input: &str) -> Result<Vec<Diagram>, Error> {
+    Ok(vec![Circuit::parse(input)?.diagram()])
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(
+        Circuit::parse,
+        part_1,
+        part_2,
+    ))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(20);
+}
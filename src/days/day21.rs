@@ -0,0 +1,570 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{days::Alternative, *};
+use enum_map::EnumMap;
+use itertools::Itertools;
+
+struct Garden {
+    map: Grid<u8>,
+    start: (usize, usize),
+}
+
+fn parse(input: &str) -> Result<Garden, Error> {
+    let map = Grid::<u8>::parse(input);
+    let start = map
+        .find(&b'S')
+        .ok_or_else(|| Error::new("no starting position"))?;
+    Ok(Garden { map, start })
+}
+
+fn part_1(garden: &Garden, steps: usize) -> usize {
+    let Garden { map: data, start } = garden;
+    let mut possible: HashSet<(usize, usize)> = HashSet::from([*start]);
+    for _ in 0..steps {
+        let mut new_possible = HashSet::new();
+        for position in possible {
+            new_possible.extend(
+                data.neighbors4(position)
+                    .filter(|&neighbor| data[neighbor] != b'#'),
+            );
+        }
+        possible = new_possible;
+    }
+    possible.len()
+}
+
+/// Counts the plots which can be reached in a fully explored universe: those connected to the
+/// start or to the universe's edges.
+fn count_odd_and_even_squares(data: &[&[u8]]) -> (usize, usize) {
+    let (sy, sx) = data
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| row.iter().position(|&c| c == b'S').map(|x| (y, x)))
+        .unwrap();
+    let h = data.len();
+    let w = data[0].len();
+    let mut reachable = vec![vec![false; w]; h];
+    let mut stack = (0..h)
+        .flat_map(|y| [(y, 0), (y, w - 1)])
+        .chain((0..w).flat_map(|x| [(0, x), (h - 1, x)]))
+        .chain([(sy, sx)])
+        .filter(|&(y, x)| data[y][x] != b'#')
+        .collect_vec();
+    while let Some((y, x)) = stack.pop() {
+        if std::mem::replace(&mut reachable[y][x], true) {
+            continue;
+        }
+        for (ny, nx) in [(y.wrapping_sub(1), x), (y + 1, x), (y, x.wrapping_sub(1)), (y, x + 1)] {
+            if ny < h && nx < w && data[ny][nx] != b'#' && !reachable[ny][nx] {
+                stack.push((ny, nx));
+            }
+        }
+    }
+    let mut odd = 0;
+    let mut even = 0;
+    for y in 0..h {
+        for x in 0..w {
+            if reachable[y][x] {
+                let y_parity = (y & 1) == (sy & 1);
+                let x_parity = (x & 1) == (sx & 1);
+                let parity = y_parity ^ x_parity;
+                if !parity {
+                    even += 1;
+                } else {
+                    odd += 1;
+                }
+            }
+        }
+    }
+    (odd, even)
+}
+
+enum Universe<'data> {
+    FullyExplored,
+    NotFullyExplored(Box<UnexploredUniverse<'data>>),
+}
+
+struct UnexploredUniverse<'data> {
+    data: &'data [&'data [u8]],
+    seen: Vec<Vec<bool>>,
+    frontier: HashSet<(usize, usize)>,
+}
+
+impl<'data> Universe<'data> {
+    fn new(data: &'data [&'data [u8]]) -> Self {
+        Universe::NotFullyExplored(Box::new(UnexploredUniverse {
+            data,
+            seen: data.iter().map(|row| vec![false; row.len()]).collect_vec(),
+            frontier: HashSet::new(),
+        }))
+    }
+
+    fn is_fully_explored(&self) -> bool {
+        matches!(self, Universe::FullyExplored)
+    }
+
+    /// Returns `((y, x), universe_direction)` for cells to be added to the frontier in different universes
+    fn step(&mut self) -> EnumMap<Direction, Vec<(usize, usize)>> {
+        let Universe::NotFullyExplored(universe) = self else {
+            return Default::default();
+        };
+        let UnexploredUniverse {
+            data,
+            ref mut seen,
+            ref mut frontier,
+        } = **universe;
+        assert!(
+            !frontier.is_empty(),
+            "should have been marked as fully explored"
+        );
+        let h = data.len();
+        let w = data[0].len();
+        let mut new_frontier: HashSet<(usize, usize)> = HashSet::with_capacity(frontier.len() * 2);
+        let mut multiverse_frontier: EnumMap<Direction, Vec<(usize, usize)>> = Default::default();
+
+        for (y, x) in std::mem::take(frontier) {
+            seen[y][x] = true;
+            {
+                // North
+                match y.checked_sub(1) {
+                    Some(y) => {
+                        if !seen[y][x] && data[y][x] != b'#' {
+                            new_frontier.insert((y, x));
+                        }
+                    }
+                    None => {
+                        multiverse_frontier[Direction::North].push((h - 1, x));
+                    }
+                }
+            }
+            {
+                // West
+                match x.checked_sub(1) {
+                    Some(x) => {
+                        if !seen[y][x] && data[y][x] != b'#' {
+                            new_frontier.insert((y, x));
+                        }
+                    }
+                    None => {
+                        multiverse_frontier[Direction::West].push((y, w - 1));
+                    }
+                }
+            }
+            {
+                // South
+                let y = y + 1;
+                if y < h {
+                    if !seen[y][x] && data[y][x] != b'#' {
+                        new_frontier.insert((y, x));
+                    }
+                } else {
+                    multiverse_frontier[Direction::South].push((0, x));
+                }
+            }
+            {
+                // East
+                let x = x + 1;
+                if x < w {
+                    if !seen[y][x] && data[y][x] != b'#' {
+                        new_frontier.insert((y, x));
+                    }
+                } else {
+                    multiverse_frontier[Direction::East].push((y, 0));
+                }
+            }
+        }
+        *frontier = new_frontier;
+        if frontier.is_empty() {
+            *self = Universe::FullyExplored;
+        }
+        multiverse_frontier
+    }
+
+    fn append_frontier(&mut self, frontier: impl IntoIterator<Item = (usize, usize)>) {
+        let Universe::NotFullyExplored(universe) = self else {
+            return;
+        };
+        universe.frontier.extend(frontier);
+    }
+}
+
+/// flip the results depending on the parity of the starting position.
+fn count_reachable_odd_and_even(
+    data: &[&[u8]],
+    universes: &HashMap<(isize, isize), Universe<'_>>,
+) -> (usize, usize) {
+    // both the example input and my input have odd width and height, so I'm just hard-coding that.
+    let h = data.len();
+    let w = data[0].len();
+    assert!(h % 2 == 1);
+    assert!(w % 2 == 1);
+
+    let (odd_parity_reachable_squares, even_parity_reachable_squares) =
+        count_odd_and_even_squares(data);
+
+    let mut odd = 0;
+    let mut even = 0;
+
+    for (&(uy, ux), universe) in universes {
+        let universe_parity = (uy & 1) != (ux & 1);
+        let (oprs, eprs) = if let Universe::NotFullyExplored(universe) = universe {
+            let mut oprs = 0;
+            let mut eprs = 0;
+            for y in 0..h {
+                for x in 0..w {
+                    let cell_parity = (x & 1) != (y & 1);
+                    if universe.seen[y][x] {
+                        if !cell_parity {
+                            eprs += 1
+                        } else {
+                            oprs += 1;
+                        }
+                    }
+                }
+            }
+            (oprs, eprs)
+        } else {
+            (odd_parity_reachable_squares, even_parity_reachable_squares)
+        };
+        if !universe_parity {
+            odd += oprs;
+            even += eprs;
+        } else {
+            even += oprs;
+            odd += eprs;
+        }
+    }
+
+    (odd, even)
+}
+
+/// How a copy of the map is counted by [`part_2`], which works out distances within the
+/// `(2 * radius + 1)²` copies centred on the start's, and extrapolates beyond them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    /// Strictly inside the searched copies, so counted directly. Those near the start are full,
+    /// alternating between the odd and even plots as their distance from the start alternates.
+    Interior,
+    /// On the edge of the searched copies: stands for it and every copy beyond it in a straight
+    /// line, each `size` steps further away than the last.
+    Edge,
+    /// A corner of the searched copies: stands for the whole quadrant beyond it, where there are
+    /// `n + 1` copies `n * size` steps further away.
+    Corner,
+}
+
+/// The distances from the start to the plots in the copies of the map around the start's, found
+/// by a single breadth-first search which is grown outward a few steps at a time.
+///
+/// The search does not go more than [`MAX_RADIUS`] copies out from the start's.
+struct TiledDistances<'a> {
+    garden: &'a Garden,
+    /// Whether each plot is connected to the start at all when the map wraps around, as plots
+    /// which are not will never be reached in any copy.
+    connected: Grid<bool>,
+    /// The distances to the plots in each copy that has been reached, by the copy's row and column
+    /// relative to the start's.
+    tiles: HashMap<(isize, isize), Grid<Option<usize>>>,
+    /// Plots whose neighbours have not been searched yet, by row and column relative to the
+    /// top-left corner of the start's copy, with their distances.
+    queue: VecDeque<((isize, isize), usize)>,
+    /// Every plot at most this many steps from the start has been found.
+    searched: usize,
+}
+
+impl<'a> TiledDistances<'a> {
+    fn new(garden: &'a Garden) -> Self {
+        let (h, w) = garden.map.size();
+        let mut connected = Grid::new(h, w, false);
+        connected[garden.start] = true;
+        let mut stack = vec![garden.start];
+        while let Some((y, x)) = stack.pop() {
+            for next in [
+                ((y + h - 1) % h, x),
+                ((y + 1) % h, x),
+                (y, (x + w - 1) % w),
+                (y, (x + 1) % w),
+            ] {
+                if garden.map[next] != b'#' && !std::mem::replace(&mut connected[next], true) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut start_tile = Grid::new(h, w, None);
+        start_tile[garden.start] = Some(0);
+        let start = (garden.start.0 as isize, garden.start.1 as isize);
+        Self {
+            garden,
+            connected,
+            tiles: HashMap::from([((0, 0), start_tile)]),
+            queue: VecDeque::from([(start, 0)]),
+            searched: 0,
+        }
+    }
+
+    /// Whether every plot that can be reached has been found.
+    fn is_finished(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// The distance to the plot at `position` in the copy `tile` copies from the start's, if it
+    /// has been found.
+    fn get(&self, tile: (isize, isize), position: (usize, usize)) -> Option<usize> {
+        self.tiles.get(&tile).and_then(|distances| distances[position])
+    }
+
+    /// Continue the search until every plot at most `searched` steps from the start is found.
+    fn search_to(&mut self, searched: usize) {
+        let (h, w) = self.garden.map.size();
+        let (h, w) = (h as isize, w as isize);
+        let limit = MAX_RADIUS as isize;
+        while let Some(&((y, x), distance)) = self.queue.front() {
+            if distance >= searched {
+                break;
+            }
+            self.queue.pop_front();
+            for Point2 { y: dy, x: dx } in Direction::ALL.map(Direction::offset) {
+                let (y, x) = (y + dy, x + dx);
+                let tile = (y.div_euclid(h), x.div_euclid(w));
+                let position = (y.rem_euclid(h) as usize, x.rem_euclid(w) as usize);
+                if tile.0.abs() > limit
+                    || tile.1.abs() > limit
+                    || self.garden.map[position] == b'#'
+                {
+                    continue;
+                }
+                let distances = self
+                    .tiles
+                    .entry(tile)
+                    .or_insert_with(|| Grid::new(h as usize, w as usize, None));
+                if distances[position].is_none() {
+                    distances[position] = Some(distance + 1);
+                    self.queue.push_back(((y, x), distance + 1));
+                }
+            }
+        }
+        self.searched = searched;
+    }
+
+    /// Whether each copy `radius` copies out from the start's is exactly `size` steps further
+    /// from the start than the copies next to it on the inside, or `None` if that depends on
+    /// plots which have not been found yet.
+    fn is_settled(&self, radius: usize, size: usize) -> Option<bool> {
+        let r = radius as isize;
+        let mut undecided = false;
+        for (ty, tx) in (-r..=r)
+            .cartesian_product(-r..=r)
+            .filter(|&(ty, tx)| ty.abs() == r || tx.abs() == r)
+        {
+            let mut inside = vec![];
+            if ty.abs() == r {
+                inside.push((ty - ty.signum(), tx));
+            }
+            if tx.abs() == r {
+                inside.push((ty, tx - tx.signum()));
+            }
+            for position in self.garden.map.positions() {
+                if !self.connected[position] {
+                    continue;
+                }
+                let actual = self.get((ty, tx), position);
+                for &inner in &inside {
+                    match (self.get(inner, position).map(|d| d + size), actual) {
+                        (Some(expected), Some(actual)) if expected == actual => {}
+                        (None, None) if self.is_finished() => {}
+                        (_, Some(_)) => return Some(false),
+                        (Some(expected), None)
+                            if expected <= self.searched || self.is_finished() =>
+                        {
+                            return Some(false)
+                        }
+                        _ => undecided = true,
+                    }
+                }
+            }
+        }
+        (!undecided).then_some(true)
+    }
+}
+
+/// How many copies of a plot `distance` steps away within the searched copies can be reached in
+/// exactly `steps` steps, counting the copies it stands for as a `tile`.
+fn reachable_copies(distance: usize, size: usize, steps: usize, tile: Tile) -> usize {
+    if distance > steps {
+        return 0;
+    }
+    let max = match tile {
+        Tile::Interior => 0,
+        Tile::Edge | Tile::Corner => (steps - distance) / size,
+    };
+    // Copy n is reachable if the steps left over when reaching it are even, so the elf can step
+    // back and forth.
+    let (first, stride) = if size.is_multiple_of(2) {
+        if !(steps - distance).is_multiple_of(2) {
+            return 0;
+        }
+        (0, 1)
+    } else {
+        ((steps - distance) % 2, 2)
+    };
+    if first > max {
+        return 0;
+    }
+    let count = (max - first) / stride + 1;
+    match tile {
+        Tile::Interior | Tile::Edge => count,
+        // The sum of n + 1 over n = first, first + stride, ...
+        Tile::Corner => count * (first + 1) + stride * count * (count - 1) / 2,
+    }
+}
+
+/// The most copies of the map out from the start's to search before giving up on the distances
+/// settling into a pattern.
+const MAX_RADIUS: usize = 8;
+
+fn part_2(garden: &Garden, steps: usize) -> Result<usize, Error> {
+    let (h, w) = garden.map.size();
+    if h != w {
+        return Err(Error::new("the map is not square"));
+    }
+    let size = h;
+
+    // Search out until each copy on the edge is exactly `size` steps further from the start than
+    // the copy inside it, so that the copies beyond can be extrapolated.
+    let mut distances = TiledDistances::new(garden);
+    let mut radius = 2;
+    loop {
+        match distances.is_settled(radius, size) {
+            Some(true) => break,
+            Some(false) if radius == MAX_RADIUS => {
+                return Err(Error::new(format!(
+                    "the distances did not settle within {MAX_RADIUS} copies of the map"
+                )))
+            }
+            Some(false) => radius += 1,
+            None => distances.search_to(distances.searched + size),
+        }
+    }
+
+    let r = radius as isize;
+    let mut total = 0;
+    for (ty, tx) in (-r..=r).cartesian_product(-r..=r) {
+        let tile = match (ty.abs() == r, tx.abs() == r) {
+            (false, false) => Tile::Interior,
+            (true, true) => Tile::Corner,
+            _ => Tile::Edge,
+        };
+        for (y, x) in garden.map.positions() {
+            if let Some(distance) = distances.get((ty, tx), (y, x)) {
+                total += reachable_copies(distance, size, steps, tile);
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Part 2 by simulating every copy of the map that the elf reaches, step by step. This is far
+/// too slow for the real number of steps, but checks [`part_2`] for small ones.
+fn simulate(garden: &Garden, steps: usize) -> usize {
+    let data = garden.map.rows().collect_vec();
+    let (y, x) = garden.start;
+
+    let mut universes: HashMap<(isize, isize), Universe<'_>> = HashMap::from([((0, 0), {
+        let mut universe = Universe::new(&data);
+        universe.append_frontier([(y, x)]);
+        universe
+    })]);
+
+    let mut frontier: HashSet<(isize, isize)> = HashSet::from([(0, 0)]);
+
+    // ..= because the first step is the one that actually places the starting position.
+    for _ in 0..=steps {
+        let mut new_frontier = HashSet::with_capacity(frontier.len());
+        let mut to_add_multiverse_frontiers: HashMap<(isize, isize), Vec<(usize, usize)>> =
+            HashMap::new();
+        for (uy, ux) in frontier {
+            let universe = universes.get_mut(&(uy, ux)).expect("universe exists");
+            let multiverse_frontier = universe.step();
+            if !universe.is_fully_explored() {
+                new_frontier.insert((uy, ux));
+            }
+            for (dir, frontier) in multiverse_frontier {
+                if !frontier.is_empty() {
+                    let Point2 { y: dy, x: dx } = dir.offset();
+                    let uy = uy + dy;
+                    let ux = ux + dx;
+                    match to_add_multiverse_frontiers.entry((uy, ux)) {
+                        std::collections::hash_map::Entry::Occupied(entry) => {
+                            entry.into_mut().extend(frontier);
+                        }
+                        std::collections::hash_map::Entry::Vacant(entry) => {
+                            entry.insert(frontier);
+                        }
+                    }
+                }
+            }
+        }
+
+        for ((uy, ux), frontier) in to_add_multiverse_frontiers {
+            if !frontier.is_empty() {
+                let adjacent_universe = universes
+                    .entry((uy, ux))
+                    .or_insert_with(|| Universe::new(&data));
+                adjacent_universe.append_frontier(frontier);
+                if !adjacent_universe.is_fully_explored() {
+                    new_frontier.insert((uy, ux));
+                }
+            }
+        }
+
+        frontier = new_frontier;
+    }
+    let (odd, even) = count_reachable_odd_and_even(&data, &universes);
+    if steps.is_multiple_of(2) {
+        even
+    } else {
+        odd
+    }
+}
+
+/// The most steps the simulation alternative will take, as it slows down quadratically.
+const MAX_SIMULATED_STEPS: usize = 5000;
+
+pub const ALTERNATIVES: &[Alternative] = &[Alternative {
+    part: 2,
+    name: "simulation",
+    run: |input, params| {
+        let steps = params.get("steps")?.unwrap_or(26501365);
+        if steps > MAX_SIMULATED_STEPS {
+            return Err(Error::new(format!(
+                "{steps} steps is too many to simulate; pass --param steps=N with N at most {MAX_SIMULATED_STEPS}"
+            )));
+        }
+        Ok(simulate(&parse(input)?, steps).to_string())
+    },
+}];
+
+/// The `steps` parameter overrides how many steps the elf takes, for both parts.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let steps = params.get("steps")?;
+    Ok(Box::new(TryPreParsed::new(
+        parse,
+        move |garden: &Garden| part_1(garden, steps.unwrap_or(64)),
+        move |garden: &Garden| part_2(garden, steps.unwrap_or(26501365)),
+    )))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(21);
+}
+
+#[test]
+fn simulation_agrees() {
+    let examples = crate::examples::load(21).unwrap();
+    let garden = parse(&examples[0].input).unwrap();
+    for steps in (0..40).chain([64, 99, 100, 131]) {
+        assert_eq!(part_2(&garden, steps), Ok(simulate(&garden, steps)), "{steps} steps");
+    }
+}
@@ -3,15 +3,10 @@ use std::{
     ops::RangeInclusive,
 };
 
-use aoc_driver::*;
+#[cfg(test)]
+use rand::Rng;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Cube {
-    x: usize,
-    y: usize,
-    z: usize,
-    brick_id: usize,
-}
+use crate::{SeparateFunctions, Solution};
 
 fn parse_xyz(s: &str) -> (usize, usize, usize) {
     let mut iter = s.split(',').map(|s| s.parse().unwrap());
@@ -120,18 +115,10 @@ fn part_1(input: &str) -> usize {
         }
     }
 
-    for (rester, restees) in &rester_to_restees {
-        eprintln!("{rester} is resting on {restees:?}");
-    }
-
-    for (restee, resters) in &restee_to_resters {
-        eprintln!("{restee} is rested on by {resters:?}");
-    }
-
     (0..brick_count)
         .filter(|brick_id| {
-            for rester in &restee_to_resters[&brick_id] {
-                if rester_to_restees[&rester].len() == 1 {
+            for rester in &restee_to_resters[brick_id] {
+                if rester_to_restees[rester].len() == 1 {
                     return false;
                 }
             }
@@ -230,14 +217,6 @@ fn part_2(input: &str) -> usize {
         }
     }
 
-    for (rester, restees) in &rester_to_restees {
-        eprintln!("{rester} is resting on {restees:?}");
-    }
-
-    for (restee, resters) in &restee_to_resters {
-        eprintln!("{restee} is rested on by {resters:?}");
-    }
-
     (0..brick_count)
         .map(|brick_id| {
             let mut would_disintegrate: BTreeSet<usize> = BTreeSet::from([brick_id]);
@@ -248,10 +227,9 @@ fn part_2(input: &str) -> usize {
                         .difference(&would_disintegrate)
                         .next()
                         .is_none()
+                        && would_disintegrate.insert(rester)
                     {
-                        if would_disintegrate.insert(rester) {
-                            queue.push_back(rester);
-                        }
+                        queue.push_back(rester);
                     }
                 }
             }
@@ -260,26 +238,103 @@ fn part_2(input: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, 2023:22:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    if let Err(error) = aoc_magic!(session, 2023:22:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
+/// A random snapshot of up to 10 bricks, each up to 3 cubes long, falling onto a 4 by 4 area.
+#[cfg(test)]
+fn random_input(rng: &mut impl Rng) -> String {
+    use std::collections::HashSet;
+
+    let count = rng.gen_range(1..=10);
+    let mut occupied = HashSet::new();
+    let mut input = String::new();
+    while input.lines().count() < count {
+        let start = [rng.gen_range(0..4), rng.gen_range(0..4), rng.gen_range(1..=12)];
+        let mut end = start;
+        let axis = rng.gen_range(0..3);
+        end[axis] = (end[axis] + rng.gen_range(0..3)).min(if axis == 2 { 14 } else { 3 });
+        let cubes = (start[0]..=end[0])
+            .flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+            .flat_map(|(x, y)| (start[2]..=end[2]).map(move |z| (x, y, z)))
+            .collect::<Vec<_>>();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+        occupied.extend(cubes);
+        let (start, end) = if rng.gen_bool(0.5) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let [x1, y1, z1] = start;
+        let [x2, y2, z2] = end;
+        input += &format!("{x1},{y1},{z1}~{x2},{y2},{z2}\n");
     }
+    input
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "1,0,1~1,2,1
-0,0,2~2,0,2
-0,2,3~2,2,3
-0,0,4~0,2,4
-2,0,5~2,2,5
-0,1,6~2,1,6
-1,1,8~1,1,9";
-    assert_eq!(part_1(input), 5);
-    assert_eq!(part_2(input), 7);
+    crate::examples::check(22);
+}
+
+/// Both parts, by letting the bricks fall one space at a time, and then doing it again without
+/// each brick in turn to see which others move.
+#[cfg(test)]
+fn brute_force(input: &str) -> (usize, usize) {
+    use std::collections::HashSet;
+
+    type Brick = ((usize, usize, usize), (usize, usize, usize));
+
+    let cubes = |&((x1, y1, z1), (x2, y2, z2)): &Brick| {
+        (x1..=x2).flat_map(move |x| (y1..=y2).flat_map(move |y| (z1..=z2).map(move |z| (x, y, z))))
+    };
+    // Which bricks fall when the others settle.
+    let settle = |bricks: &mut Vec<Brick>| {
+        let mut fell = BTreeSet::new();
+        loop {
+            let occupied: HashSet<_> = bricks.iter().flat_map(cubes).collect();
+            let falling = (0..bricks.len()).find(|&i| {
+                let ((x1, y1, z1), (x2, y2, _)) = bricks[i];
+                let below = ((x1, y1, z1.wrapping_sub(1)), (x2, y2, z1.wrapping_sub(1)));
+                z1 > 0 && cubes(&below).all(|cube| !occupied.contains(&cube))
+            });
+            let Some(i) = falling else {
+                return fell;
+            };
+            bricks[i].0 .2 -= 1;
+            bricks[i].1 .2 -= 1;
+            fell.insert(i);
+        }
+    };
+
+    let mut bricks: Vec<Brick> = input
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once('~').unwrap();
+            let (start, end) = (parse_xyz(start), parse_xyz(end));
+            (start.min(end), start.max(end))
+        })
+        .collect();
+    settle(&mut bricks);
+    let falls = (0..bricks.len())
+        .map(|i| {
+            let mut others = bricks.clone();
+            others.remove(i);
+            settle(&mut others).len()
+        })
+        .collect::<Vec<_>>();
+    (
+        falls.iter().filter(|&&falls| falls == 0).count(),
+        falls.iter().sum(),
+    )
+}
+
+#[test]
+fn fuzz() {
+    crate::fuzz::check(100, random_input, brute_force, |input| {
+        (part_1(input), part_2(input))
+    });
 }
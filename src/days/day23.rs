@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use crate::*;
+
+/// A corridor leading out of a junction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    /// The node at the other end.
+    pub to: usize,
+    /// How many steps the corridor takes.
+    pub length: usize,
+    /// Whether the corridor can be walked this way when slopes can only be walked down.
+    pub downhill: bool,
+}
+
+/// The trail map compressed to its junctions, with an edge for each corridor between them.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    /// The position of each node: the start, the end, and every junction.
+    pub nodes: Vec<(usize, usize)>,
+    /// `edges[node]` lists the corridors leading out of each node. Every corridor appears once
+    /// from each end.
+    pub edges: Vec<Vec<Edge>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The direction a slope tile points, if it is one.
+fn slope(tile: u8) -> Option<Direction> {
+    match tile {
+        b'^' => Some(Direction::North),
+        b'>' => Some(Direction::East),
+        b'v' => Some(Direction::South),
+        b'<' => Some(Direction::West),
+        _ => None,
+    }
+}
+
+impl Graph {
+    pub fn new(map: &Grid<u8>) -> Result<Self, Error> {
+        let (h, w) = map.size();
+        let open = |position: (usize, usize)| map[position] != b'#';
+        let gap = |row: usize| {
+            (0..w)
+                .map(|col| (row, col))
+                .find(|&position| open(position))
+                .ok_or_else(|| Error::new(format!("no gap in row {row}")))
+        };
+        if h == 0 {
+            return Err(Error::new("empty map"));
+        }
+        let start = gap(0)?;
+        let end = gap(h - 1)?;
+
+        let mut nodes = vec![start, end];
+        nodes.extend(map.positions().filter(|&position| {
+            open(position) && map.neighbors4(position).filter(|&next| open(next)).count() > 2
+        }));
+        let index: HashMap<(usize, usize), usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(node, &position)| (position, node))
+            .collect();
+
+        let edges = nodes
+            .iter()
+            .map(|&from| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(|direction| {
+                        // Follow the corridor until it reaches another node, noting whether every
+                        // slope along it is walked downhill.
+                        let mut position = map.offset(from, direction).filter(|&p| open(p))?;
+                        let mut previous = from;
+                        let mut length = 1;
+                        let mut downhill = slope(map[from]).is_none_or(|s| s == direction);
+                        while !index.contains_key(&position) {
+                            let (next_direction, next) = Direction::ALL.into_iter().find_map(|d| {
+                                map.offset(position, d)
+                                    .filter(|&next| next != previous && open(next))
+                                    .map(|next| (d, next))
+                            })?;
+                            downhill &= slope(map[position]).is_none_or(|s| s == next_direction);
+                            (previous, position) = (position, next);
+                            length += 1;
+                        }
+                        let to = index[&position];
+                        (position != from).then_some(Edge {
+                            to,
+                            length,
+                            downhill,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            nodes,
+            edges,
+            start: 0,
+            end: 1,
+        })
+    }
+
+    /// The length of the longest hike from the start to the end which never visits a tile twice,
+    /// if there is one. If `slippery`, slopes can only be walked down.
+    pub fn longest_hike(&self, slippery: bool) -> Result<usize, Error> {
+        if self.nodes.len() > 64 {
+            return Err(Error::new(format!(
+                "{} junctions is too many to search",
+                self.nodes.len()
+            )));
+        }
+        let edges = self
+            .edges
+            .iter()
+            .map(|edges| {
+                edges
+                    .iter()
+                    .filter(|edge| edge.downhill || !slippery)
+                    .map(|edge| (edge.to, edge.length))
+                    .collect_vec()
+            })
+            .collect_vec();
+        search::longest_path(&edges, self.start, self.end)
+            .ok_or_else(|| Error::new("no hike reaches the end"))
+    }
+
+    /// The graph as a diagram. Corridors which can only be walked one way down their slopes are
+    /// drawn as arrows, and corridors which cannot be walked either way are dashed.
+    pub fn diagram(&self) -> Diagram {
+        let mut diagram = Diagram::new("junctions");
+        for (node, &(row, col)) in self.nodes.iter().enumerate() {
+            let label = match node {
+                _ if node == self.start => "start ",
+                _ if node == self.end => "end ",
+                _ => "",
+            };
+            diagram.node(node).label(format!("{label}({row}, {col})"));
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for edge in edges {
+                let back = self.edges[edge.to]
+                    .iter()
+                    .find(|back| back.to == from && back.length == edge.length)
+                    .is_some_and(|back| back.downhill);
+                match (edge.downhill, back) {
+                    (true, true) if from < edge.to => {
+                        diagram.edge(from, edge.to).label(edge.length).undirected();
+                    }
+                    (true, false) => {
+                        diagram.edge(from, edge.to).label(edge.length);
+                    }
+                    (false, false) if from < edge.to => {
+                        diagram
+                            .edge(from, edge.to)
+                            .label(edge.length)
+                            .undirected()
+                            .dashed();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        diagram
+    }
+}
+
+fn parse(input: &str) -> Result<Graph, Error> {
+    Graph::new(&Grid::<u8>::parse(input))
+}
+
+fn part_1(graph: &Graph) -> Result<usize, Error> {
+    graph.longest_hike(true)
+}
+
+fn part_2(graph: &Graph) -> Result<usize, Error> {
+    graph.longest_hike(false)
+}
+
+pub fn graphs(input: &str) -> Result<Vec<Diagram>, Error> {
+    Ok(vec![parse(input)?.diagram()])
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(23);
+}
+
+#[test]
+fn graph() {
+    let examples = crate::examples::load(23).unwrap();
+    let graph = parse(&examples[1].input).unwrap();
+    assert_eq!(graph.nodes.len(), 9);
+    assert_eq!((graph.nodes[graph.start], graph.nodes[graph.end]), ((0, 1), (22, 21)));
+    let [edge] = graph.edges[graph.start][..] else {
+        panic!("expected one corridor from the start");
+    };
+    assert_eq!((graph.nodes[edge.to], edge.length, edge.downhill), ((5, 3), 15, true));
+    let dot = graph.diagram().to_dot();
+    assert!(dot.starts_with("digraph \"junctions\" {\n    \"0\" [label=\"start (0, 1)\"];\n"));
+    assert!(dot.contains(&format!("    \"0\" -> \"{}\" [label=\"15\"];\n", edge.to)));
+}
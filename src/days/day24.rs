@@ -0,0 +1,655 @@
+use std::fmt;
+
+use crate::{
+    days::Alternative,
+    parse_field, rational::Rational, try_parse_lines_located, Error, Params, Solution,
+    TryPreParsed,
+};
+use glam::I64Vec3;
+use itertools::Itertools;
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    position: I64Vec3,
+    velocity: I64Vec3,
+}
+
+impl Hailstone {
+    /// Where the hailstone is at time `n`, or `None` if that does not fit in an `i64`.
+    fn t(self, n: i64) -> Option<I64Vec3> {
+        let at = |position: i64, velocity: i64| {
+            velocity
+                .checked_mul(n)
+                .and_then(|offset| offset.checked_add(position))
+        };
+        Some(I64Vec3::new(
+            at(self.position.x, self.velocity.x)?,
+            at(self.position.y, self.velocity.y)?,
+            at(self.position.z, self.velocity.z)?,
+        ))
+    }
+
+    fn as_solution(self) -> i64 {
+        let I64Vec3 { x, y, z } = self.position;
+        x + y + z
+    }
+}
+
+impl fmt::Display for Hailstone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let I64Vec3 { x, y, z } = self.position;
+        let I64Vec3 {
+            x: vx,
+            y: vy,
+            z: vz,
+        } = self.velocity;
+        write!(f, "{x}, {y}, {z} @ {vx}, {vy}, {vz}")
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Hailstone>, Error> {
+    let parse_vec3 = |s: &str, line: &str| {
+        let [x, y, z] = s
+            .split(',')
+            .map(|field| parse_field(field.trim(), line))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| Error::new("expected three coordinates").at(s, line))?;
+        Ok::<_, Error>(I64Vec3 { x, y, z })
+    };
+    try_parse_lines_located(input, |line| {
+        let (pos, vel) = line
+            .split_once('@')
+            .ok_or_else(|| Error::new("expected '@'").at(line, line))?;
+        Ok(Hailstone {
+            position: parse_vec3(pos, line)?,
+            velocity: parse_vec3(vel, line)?,
+        })
+    })
+}
+
+/// The square of X and Y coordinates, inclusive, in which part 1 counts crossings.
+#[derive(Debug, Clone, Copy)]
+struct TestArea {
+    min: i64,
+    max: i64,
+}
+
+impl TestArea {
+    fn contains(&self, x: &Rational, y: &Rational) -> bool {
+        let (min, max) = (Rational::from(self.min), Rational::from(self.max));
+        (&min..=&max).contains(&x) && (&min..=&max).contains(&y)
+    }
+}
+
+/// Where the paths of two hailstones cross, ignoring the Z axis.
+#[derive(Debug, Clone)]
+struct Crossing {
+    x: Rational,
+    y: Rational,
+    /// When each hailstone is at the crossing point. Negative times are in the past.
+    times: [Rational; 2],
+}
+
+/// Where the paths of `h1` and `h2` cross in X and Y, or `None` if they are parallel.
+fn crossing(h1: Hailstone, h2: Hailstone) -> Option<Crossing> {
+    // x0_1 + t1*vx_1 = x0_2 + t2*vx_2
+    // y0_1 + t1*vy_1 = y0_2 + t2*vy_2
+    // is a system of two linear equations in t1 and t2, which Cramer's rule solves exactly
+    // unless the velocities are parallel.
+    let [x0_1, y0_1, _] = h1.position.to_array().map(i128::from);
+    let [vx_1, vy_1, _] = h1.velocity.to_array().map(i128::from);
+    let [x0_2, y0_2, _] = h2.position.to_array().map(i128::from);
+    let [vx_2, vy_2, _] = h2.velocity.to_array().map(i128::from);
+    let (dx, dy) = (x0_2 - x0_1, y0_2 - y0_1);
+    let det = vx_2 * vy_1 - vx_1 * vy_2;
+    if det == 0 {
+        return None;
+    }
+    let t1 = Rational::new(BigInt::from(vx_2 * dy - vy_2 * dx), BigInt::from(det));
+    let t2 = Rational::new(BigInt::from(vx_1 * dy - vy_1 * dx), BigInt::from(det));
+    let x = Rational::from(BigInt::from(x0_1)) + &t1 * Rational::from(BigInt::from(vx_1));
+    let y = Rational::from(BigInt::from(y0_1)) + &t1 * Rational::from(BigInt::from(vy_1));
+    Some(Crossing {
+        x,
+        y,
+        times: [t1, t2],
+    })
+}
+
+/// Describes whether and where the paths of hailstones `a` and `b` cross, like the puzzle's
+/// walkthrough of the example.
+fn describe_crossing(a: Hailstone, b: Hailstone, test_area: TestArea) -> String {
+    let Some(Crossing { x, y, times }) = crossing(a, b) else {
+        return format!("A: {a}\nB: {b}\nPaths are parallel; they never intersect.");
+    };
+    let when = |name, time: &Rational| {
+        let past = if time.is_negative() { " (in the past)" } else { "" };
+        format!("hailstone {name} at t={time}{past}")
+    };
+    let place = if test_area.contains(&x, &y) {
+        "inside"
+    } else {
+        "outside"
+    };
+    format!(
+        "A: {a}\nB: {b}\nPaths cross {place} the test area at x={x}, y={y}: {}, {}.",
+        when("A", &times[0]),
+        when("B", &times[1]),
+    )
+}
+
+/// Counts the pairs of hailstones whose paths cross within the test area, in the future for
+/// both hailstones.
+fn part_1(hailstones: &Vec<Hailstone>, test_area: TestArea) -> usize {
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|&(&h1, &h2)| {
+            crossing(h1, h2).is_some_and(|Crossing { x, y, times }| {
+                !times.iter().any(Rational::is_negative) && test_area.contains(&x, &y)
+            })
+        })
+        .count()
+}
+
+fn intersect2(h1: Hailstone, h2: Hailstone) -> bool {
+    let Hailstone {
+        position: I64Vec3 {
+            x: x0_1,
+            y: y0_1,
+            z: z0_1,
+        },
+        velocity: I64Vec3 {
+            x: vx_1,
+            y: vy_1,
+            z: vz_1,
+        },
+    } = h1;
+
+    let Hailstone {
+        position: I64Vec3 {
+            x: x0_2,
+            y: y0_2,
+            z: z0_2,
+        },
+        velocity: I64Vec3 {
+            x: vx_2,
+            y: vy_2,
+            z: vz_2,
+        },
+    } = h2;
+
+    // None means IDK,
+    // Some(Err) means impossible
+    // Some(Ok(t)) means if it is possible it happens at time t
+    let check = |p0_1: i64, v1: i64, p0_2: i64, v2: i64| -> Option<Result<i64, ()>> {
+        let num = i128::from(p0_2) - i128::from(p0_1);
+        let denom = i128::from(v1) - i128::from(v2);
+        match (num, denom) {
+            (0, 0) => None,
+            (_, 0) => Some(Err(())),
+            _ => Some(i64::try_from(num / denom).map_err(|_| ())),
+        }
+    };
+
+    let t = check(x0_1, vx_1, x0_2, vx_2)
+        .or_else(|| check(y0_1, vy_1, y0_2, vy_2))
+        .or_else(|| check(z0_1, vz_1, z0_2, vz_2))
+        .unwrap_or(Ok(0));
+
+    match t {
+        Ok(t) => h1.t(t).is_some_and(|position| Some(position) == h2.t(t)),
+        Err(_) => false,
+    }
+}
+
+// we could consider part 2 as a system of 900 equations (x,y,z for each hailstone)
+// in 306 unknowns (t for collision with each hailstone, plus x,y,z position  and x,y,z velocity of the rock)
+// rx0 + t0*rvx = h0x0 + t0*h0vx
+// ry0 + t0*rvy = h0y0 + t0*h0vy
+// rz0 + t0*rvz = h0z0 + t0*h0vz
+// rx0 + t1*rvx = h1x0 + t1*h1vx
+// ry0 + t1*rvy = h1y0 + t1*h1vy
+// rz0 + t1*rvz = h1z0 + t1*h1vz
+// etc
+// in general, if we consider n hailstones, we have a system of 3n equations to find n+6 unknowns,
+// which is solvable if 3n>=n+6, i.e. n=3.
+// ~~assuming that the input is actually solvable, it shouldn't matter which 3 hailstones we choose to solve with.~~
+// actually that's not true, e.g. if the three hailstones we choose have the same velocity, then there are multiple solutions.
+// Also, this is not a system of *linear* equations, since we are multiplying some of the variables together,
+// so the "it should be solvable" doesn't necessarily apply.
+// Without z3, we can instead use the fact that the rock's position relative to each hailstone is always
+// parallel to their relative velocity, so (P - p_i) x (V - v_i) = 0, i.e.
+// P x V - P x v_i - p_i x V + p_i x v_i = 0.
+// The only nonlinear term, P x V, is the same for every hailstone, so subtracting hailstone 0's equation
+// from every other hailstone's equation gives a system of *linear* equations in P and V:
+// P x (v_i - v_0) + (p_i - p_0) x V = p_i x v_i - p_0 x v_0
+
+#[cfg(not(feature = "day24part2"))]
+fn solve_rock(hailstones: &[Hailstone]) -> Result<Hailstone, Error> {
+    use crate::rational::{solve_linear, LinearSystemError};
+
+    let [first, rest @ ..] = hailstones else {
+        return Err(Error::new("there are no hailstones"));
+    };
+    if rest.is_empty() {
+        return Err(Error::new(
+            "there is only one hailstone, so it does not determine a single rock trajectory",
+        ));
+    }
+    let p0 = first.position;
+    let v0 = first.velocity;
+    let mut coefficients = vec![];
+    let mut constants = vec![];
+    for hailstone in rest {
+        let (p, v) = (hailstone.position, hailstone.velocity);
+        let [wx, wy, wz] = (v - v0).to_array().map(i128::from);
+        let [dx, dy, dz] = (p - p0).to_array().map(i128::from);
+        let cross = |a: I64Vec3, b: I64Vec3| {
+            let [ax, ay, az] = a.to_array().map(i128::from);
+            let [bx, by, bz] = b.to_array().map(i128::from);
+            [ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx]
+        };
+        let [c0, c1, c2] = cross(p, v);
+        let [d0, d1, d2] = cross(p0, v0);
+        // Unknowns: [Px, Py, Pz, Vx, Vy, Vz]
+        let rows = [
+            ([0, wz, -wy, 0, -dz, dy], c0 - d0),
+            ([-wz, 0, wx, dz, 0, -dx], c1 - d1),
+            ([wy, -wx, 0, -dy, dx, 0], c2 - d2),
+        ];
+        for (row, constant) in rows {
+            coefficients.push(row.map(|x| Rational::from(BigInt::from(x))).to_vec());
+            constants.push(Rational::from(BigInt::from(constant)));
+        }
+    }
+
+    let solution = solve_linear(coefficients, constants).map_err(|error| match error {
+        LinearSystemError::Inconsistent => Error::new("no rock trajectory hits every hailstone"),
+        LinearSystemError::Underdetermined => Error::new(
+            "the hailstones are degenerate (e.g. parallel), so they do not determine a single rock trajectory",
+        ),
+    })?;
+    let [px, py, pz, vx, vy, vz] = <[i64; 6]>::try_from(
+        solution
+            .iter()
+            .map(|value| {
+                value
+                    .to_integer()
+                    .and_then(|value| i64::try_from(value).ok())
+                    .ok_or_else(|| {
+                        Error::new(format!(
+                            "the rock trajectory {} is not integral",
+                            solution.iter().join(", ")
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    )
+    .map_err(|solution| {
+        Error::new(format!(
+            "expected the rock's 6 coordinates, but solved for {}",
+            solution.len()
+        ))
+    })?;
+    Ok(Hailstone {
+        position: I64Vec3::new(px, py, pz),
+        velocity: I64Vec3::new(vx, vy, vz),
+    })
+}
+
+#[cfg(feature = "day24part2")]
+fn solve_rock(hailstones: &[Hailstone]) -> Result<Hailstone, Error> {
+    for i in 0..hailstones.len() {
+        for j in i + 1..hailstones.len() {
+            for k in j + 1..hailstones.len() {
+                if let Some(rock) = try_solve2(hailstones[i], hailstones[j], hailstones[k])? {
+                    return Ok(rock);
+                }
+            }
+        }
+    }
+    Err(Error::new("no rock trajectory hits every hailstone"))
+}
+
+#[cfg(feature = "day24part2")]
+fn try_solve2(h0: Hailstone, h1: Hailstone, h2: Hailstone) -> Result<Option<Hailstone>, Error> {
+    // unknowns: rx0, ry0, rz0, rvx, rvy, rvz, t0, t1, t2
+    // equations:
+    //  A: rx0 + t0*rvx = h0x0 + t0*h0vx
+    //  B: ry0 + t0*rvy = h0y0 + t0*h0vy
+    //  C: rz0 + t0*rvz = h0z0 + t0*h0vz
+    //  D: rx0 + t1*rvx = h1x0 + t1*h1vx
+    //  E: ry0 + t1*rvy = h1y0 + t1*h1vy
+    //  F: rz0 + t1*rvz = h1z0 + t1*h1vz
+    //  G: rx0 + t2*rvx = h2x0 + t2*h2vx
+    //  H: ry0 + t2*rvy = h2y0 + t2*h2vy
+    //  I: rz0 + t2*rvz = h2z0 + t2*h2vz
+
+    let Hailstone {
+        position: I64Vec3 {
+            x: h0x0,
+            y: h0y0,
+            z: h0z0,
+        },
+        velocity: I64Vec3 {
+            x: h0vx,
+            y: h0vy,
+            z: h0vz,
+        },
+    } = h0;
+
+    let Hailstone {
+        position: I64Vec3 {
+            x: h1x0,
+            y: h1y0,
+            z: h1z0,
+        },
+        velocity: I64Vec3 {
+            x: h1vx,
+            y: h1vy,
+            z: h1vz,
+        },
+    } = h1;
+
+    let Hailstone {
+        position: I64Vec3 {
+            x: h2x0,
+            y: h2y0,
+            z: h2z0,
+        },
+        velocity: I64Vec3 {
+            x: h2vx,
+            y: h2vy,
+            z: h2vz,
+        },
+    } = h2;
+
+    use z3::{ast::Int, Config, Context};
+    let mut cfg = Config::new();
+    cfg.set_model_generation(true);
+    let ctx = Context::new(&cfg);
+
+    macro_rules! vars {
+        ($($name:ident),* $(,)?) => {
+            $(
+                let $name = Int::new_const(&ctx, stringify!($name));
+            )*
+        };
+    }
+
+    vars! {
+        rx0, ry0, rz0,
+        rvx, rvy, rvz,
+        t1, t2, t0,
+    }
+
+    macro_rules! consts {
+        ($($name:ident),* $(,)?) => {
+            $(
+                let $name = Int::from_i64(&ctx, $name);
+            )*
+        };
+    }
+
+    consts! {
+        h0x0, h0y0, h0z0,
+        h0vx, h0vy, h0vz,
+        h1x0, h1y0, h1z0,
+        h1vx, h1vy, h1vz,
+        h2x0, h2y0, h2z0,
+        h2vx, h2vy, h2vz,
+    }
+
+    //  A: rx0 + t0*rvx = h0x0 + t0*h0vx
+    //  B: ry0 + t0*rvy = h0y0 + t0*h0vy
+    //  C: rz0 + t0*rvz = h0z0 + t0*h0vz
+    //  D: rx0 + t1*rvx = h1x0 + t1*h1vx
+    //  E: ry0 + t1*rvy = h1y0 + t1*h1vy
+    //  F: rz0 + t1*rvz = h1z0 + t1*h1vz
+    //  G: rx0 + t2*rvx = h2x0 + t2*h2vx
+    //  H: ry0 + t2*rvy = h2y0 + t2*h2vy
+    //  I: rz0 + t2*rvz = h2z0 + t2*h2vz
+    let solver = z3::Solver::new(&ctx);
+
+    macro_rules! equations {
+        () => {};
+        ($r0:ident + $t:ident * $rv:ident = $h0:ident + $_t:ident * $hv:ident; $($rest:tt)*) => {
+            let lhs_product = Int::mul(&ctx, &[&$t, &$rv]);
+            let lhs = Int::add(&ctx, &[&$r0, &lhs_product]);
+            let rhs_product = Int::mul(&ctx, &[&$t, &$hv]);
+            let rhs = Int::add(&ctx, &[&$h0, &rhs_product]);
+            solver.assert(&<Int as z3::ast::Ast>::_eq(&lhs, &rhs));
+            equations!($($rest)*);
+        };
+    }
+
+    equations! {
+        rx0 + t0*rvx = h0x0 + t0*h0vx;
+        ry0 + t0*rvy = h0y0 + t0*h0vy;
+        rz0 + t0*rvz = h0z0 + t0*h0vz;
+        rx0 + t1*rvx = h1x0 + t1*h1vx;
+        ry0 + t1*rvy = h1y0 + t1*h1vy;
+        rz0 + t1*rvz = h1z0 + t1*h1vz;
+        rx0 + t2*rvx = h2x0 + t2*h2vx;
+        ry0 + t2*rvy = h2y0 + t2*h2vy;
+        rz0 + t2*rvz = h2z0 + t2*h2vz;
+    }
+
+    let zero = Int::from_u64(&ctx, 0);
+    macro_rules! constraints {
+        ($($t:ident),*) => {
+            $(
+                solver.assert(&Int::ge(&$t, &zero));
+            )*
+        };
+    }
+    constraints!(t0, t1, t2);
+
+    let result = solver.check();
+
+    match result {
+        z3::SatResult::Unsat => Ok(None),
+        z3::SatResult::Unknown => Err(Error::new(
+            "z3 could not decide whether a rock trajectory hits three of the hailstones",
+        )),
+        z3::SatResult::Sat => {
+            let Some(model) = solver.get_model() else {
+                return Ok(None);
+            };
+            macro_rules! get_values {
+                ($($value:ident),* $(,)?) => {
+                    $(
+                        let $value = model.eval(&$value, true).unwrap().as_i64().unwrap();
+                    )*
+                };
+            }
+            get_values! {
+                rx0, ry0, rz0,
+                rvx, rvy, rvz,
+            }
+            Ok(Some(Hailstone {
+                position: I64Vec3 {
+                    x: rx0,
+                    y: ry0,
+                    z: rz0,
+                },
+                velocity: I64Vec3 {
+                    x: rvx,
+                    y: rvy,
+                    z: rvz,
+                },
+            }))
+        }
+    }
+}
+
+fn part_2(hailstones: &Vec<Hailstone>) -> Result<i64, Error> {
+    let rock = solve_rock(hailstones)?;
+    if hailstones
+        .iter()
+        .all(|&hailstone| intersect2(rock, hailstone))
+    {
+        Ok(rock.as_solution())
+    } else {
+        Err(Error::new("no rock trajectory hits every hailstone"))
+    }
+}
+
+/// The fastest rock [`part_2_velocity_search`] tries, in each of X and Y.
+const MAX_SEARCH_SPEED: i64 = 300;
+
+/// Part 2 by trying every small rock velocity in X and Y. Relative to the rock, every hailstone
+/// passes through the rock's starting position, so that is where the first two hailstones' paths
+/// cross; the Z velocity then follows from when they get there.
+fn part_2_velocity_search(hailstones: &[Hailstone]) -> Result<i64, Error> {
+    let [h0, h1, ..] = *hailstones else {
+        return Err(Error::new("need at least two hailstones"));
+    };
+    let integer = |value: &Rational| value.to_integer().and_then(|value| i64::try_from(value).ok());
+    for vx in -MAX_SEARCH_SPEED..=MAX_SEARCH_SPEED {
+        for vy in -MAX_SEARCH_SPEED..=MAX_SEARCH_SPEED {
+            let rock_velocity = I64Vec3::new(vx, vy, 0);
+            let relative = |h: Hailstone| Hailstone {
+                position: h.position,
+                velocity: h.velocity - rock_velocity,
+            };
+            let Some(Crossing { x, y, times }) = crossing(relative(h0), relative(h1)) else {
+                continue;
+            };
+            let (Some(px), Some(py), Some(t0), Some(t1)) = (
+                integer(&x),
+                integer(&y),
+                integer(&times[0]),
+                integer(&times[1]),
+            ) else {
+                continue;
+            };
+            if t0 < 0 || t1 < 0 || t0 == t1 {
+                continue;
+            }
+            // pz = h0.z + t0 * (h0.vz - vz) = h1.z + t1 * (h1.vz - vz)
+            // The candidate times can be far too large, so skip any which overflow.
+            let (Some(p0), Some(p1)) = (h0.t(t0), h1.t(t1)) else {
+                continue;
+            };
+            let numerator = i128::from(p0.z) - i128::from(p1.z);
+            let elapsed = i128::from(t0) - i128::from(t1);
+            if numerator % elapsed != 0 {
+                continue;
+            }
+            let Ok(vz) = i64::try_from(numerator / elapsed) else {
+                continue;
+            };
+            let Some(pz) = h0
+                .velocity
+                .z
+                .checked_sub(vz)
+                .and_then(|relative| relative.checked_mul(t0))
+                .and_then(|offset| offset.checked_add(h0.position.z))
+            else {
+                continue;
+            };
+            let rock = Hailstone {
+                position: I64Vec3::new(px, py, pz),
+                velocity: I64Vec3::new(vx, vy, vz),
+            };
+            if hailstones
+                .iter()
+                .all(|&hailstone| intersect2(rock, hailstone))
+            {
+                return Ok(rock.as_solution());
+            }
+        }
+    }
+    Err(Error::new(format!(
+        "no rock with X and Y speeds up to {MAX_SEARCH_SPEED} hits every hailstone"
+    )))
+}
+
+pub const ALTERNATIVES: &[Alternative] = &[Alternative {
+    part: 2,
+    name: "velocity search",
+    run: |input, _| Ok(part_2_velocity_search(&parse(input)?)?.to_string()),
+}];
+
+/// The part 1 test area, whose bounds the `test_area_min` and `test_area_max` parameters override.
+fn test_area(params: &Params) -> Result<TestArea, Error> {
+    Ok(TestArea {
+        min: params.get("test_area_min")?.unwrap_or(200000000000000),
+        max: params.get("test_area_max")?.unwrap_or(400000000000000),
+    })
+}
+
+/// Describes whether and where each pair of hailstones' paths cross, for `cargo aoc --report`.
+pub fn report(input: &str, params: &Params) -> Result<String, Error> {
+    let test_area = test_area(params)?;
+    let hailstones = parse(input)?;
+    Ok(hailstones
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| describe_crossing(a, b, test_area))
+        .join("\n\n"))
+}
+
+/// The `test_area_min` and `test_area_max` parameters override the bounds of the part 1 test
+/// area.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let test_area = test_area(params)?;
+    Ok(Box::new(TryPreParsed::new(
+        parse,
+        move |hailstones: &Vec<Hailstone>| part_1(hailstones, test_area),
+        part_2,
+    )))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(24);
+}
+
+#[test]
+fn crossing_report() {
+    let input = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n20, 19, 15 @ 1, -5, -3";
+    let params = Params::new()
+        .with("test_area_min", 7)
+        .with("test_area_max", 27);
+    let report = report(input, &params).unwrap();
+    let pairs = report.split("\n\n").collect_vec();
+    assert_eq!(pairs.len(), 3);
+    assert_eq!(
+        pairs[0],
+        "A: 19, 13, 30 @ -2, 1, -2\nB: 18, 19, 22 @ -1, -1, -2\n\
+         Paths cross inside the test area at x=43/3, y=46/3: hailstone A at t=7/3, hailstone B at t=11/3."
+    );
+    assert_eq!(
+        pairs[1],
+        "A: 19, 13, 30 @ -2, 1, -2\nB: 20, 19, 15 @ 1, -5, -3\n\
+         Paths cross inside the test area at x=193/9, y=106/9: \
+         hailstone A at t=-11/9 (in the past), hailstone B at t=13/9."
+    );
+}
+
+#[cfg(not(feature = "day24part2"))]
+#[test]
+fn parallel_hailstones() {
+    let hailstones = parse("0, 0, 0 @ 1, 1, 1\n1, 0, 0 @ 1, 1, 1\n2, 0, 0 @ 1, 1, 1").unwrap();
+    assert_eq!(
+        part_2(&hailstones).unwrap_err().to_string(),
+        "the hailstones are degenerate (e.g. parallel), so they do not determine a single rock trajectory"
+    );
+    let hailstones = parse("19, 13, 30 @ -2, 1, -2").unwrap();
+    assert_eq!(
+        part_2(&hailstones).unwrap_err().to_string(),
+        "there is only one hailstone, so it does not determine a single rock trajectory"
+    );
+}
+
+#[test]
+fn velocity_search() {
+    let examples = crate::examples::load(24).unwrap();
+    let hailstones = parse(&examples[0].input).unwrap();
+    assert_eq!(part_2_velocity_search(&hailstones).unwrap(), 47);
+}
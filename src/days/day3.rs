@@ -0,0 +1,91 @@
+use crate::{Grid, SeparateFunctions, Solution};
+use itertools::Itertools;
+
+fn is_symbol(b: u8) -> bool {
+    !b.is_ascii_digit() && b != b'.'
+}
+
+struct Number {
+    value: u64,
+    /// The positions of its digits.
+    cells: Vec<(usize, usize)>,
+}
+
+/// Every number on the board, in reading order.
+fn numbers(board: &Grid<u8>) -> Vec<Number> {
+    let mut numbers = vec![];
+    for (row_idx, row) in board.rows().enumerate() {
+        let mut col = 0;
+        while col < row.len() {
+            let digits = row[col..].iter().take_while(|b| b.is_ascii_digit()).count();
+            if digits == 0 {
+                col += 1;
+                continue;
+            }
+            numbers.push(Number {
+                value: row[col..col + digits]
+                    .iter()
+                    .fold(0, |value, &digit| value * 10 + u64::from(digit - b'0')),
+                cells: (col..col + digits).map(|col| (row_idx, col)).collect(),
+            });
+            col += digits;
+        }
+    }
+    numbers
+}
+
+fn part_1(input: &str) -> u64 {
+    let board = Grid::parse_with(input, |b| b);
+    numbers(&board)
+        .iter()
+        .filter(|number| {
+            number
+                .cells
+                .iter()
+                .flat_map(|&cell| board.neighbors8(cell))
+                .any(|neighbor| is_symbol(board[neighbor]))
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part_2(input: &str) -> u64 {
+    let board = Grid::parse_with(input, |b| b);
+    let numbers = numbers(&board);
+    // Which number, if any, each cell is a digit of.
+    let mut owners = board.map(|_| None);
+    for (idx, number) in numbers.iter().enumerate() {
+        for &cell in &number.cells {
+            owners[cell] = Some(idx);
+        }
+    }
+    board
+        .iter()
+        .filter(|&(_, &b)| b == b'*')
+        .filter_map(|(gear, _)| {
+            let adjacent = board
+                .neighbors8(gear)
+                .filter_map(|neighbor| owners[neighbor])
+                .unique()
+                .collect_vec();
+            (adjacent.len() == 2).then(|| adjacent.iter().map(|&idx| numbers[idx].value).product::<u64>())
+        })
+        .sum()
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(3);
+}
+
+#[test]
+fn edges() {
+    // Gears and numbers on the first and last rows and columns.
+    let input = "2*3\n...\n4.5\n*..";
+    assert_eq!(part_1(input), 2 + 3 + 4);
+    assert_eq!(part_2(input), 2 * 3);
+}
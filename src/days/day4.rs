@@ -0,0 +1,81 @@
+use crate::*;
+
+struct Card {
+    winning: Vec<u32>,
+    have: Vec<u32>,
+}
+
+impl Card {
+    fn matching_cards(&self) -> usize {
+        let mut have_winning = 0;
+        let mut hidx = 0;
+        let mut widx = 0;
+        while hidx < self.have.len() && widx < self.winning.len() {
+            match Ord::cmp(&self.have[hidx], &self.winning[widx]) {
+                std::cmp::Ordering::Less => hidx += 1,
+                std::cmp::Ordering::Equal => {
+                    have_winning += 1;
+                    hidx += 1;
+                    widx += 1;
+                }
+                std::cmp::Ordering::Greater => widx += 1,
+            }
+        }
+        have_winning
+    }
+
+    fn point_value(&self) -> u64 {
+        (1 << self.matching_cards()) / 2
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Card>, Error> {
+    try_parse_lines_located(input, |line| {
+        let (_card_and_idx, rest) = line
+            .split_once(':')
+            .ok_or_else(|| Error::new("expected ':'").at(line, line))?;
+        let (winning, have) = rest
+            .split_once('|')
+            .ok_or_else(|| Error::new("expected '|'").at(rest, line))?;
+        let mut winning: Vec<u32> = winning
+            .split_whitespace()
+            .map(|field| parse_field(field, line))
+            .collect::<Result<_, _>>()?;
+        winning.sort_unstable();
+        let mut have: Vec<u32> = have
+            .split_whitespace()
+            .map(|field| parse_field(field, line))
+            .collect::<Result<_, _>>()?;
+        have.sort_unstable();
+        Ok(Card { winning, have })
+    })
+}
+
+fn part_1(cards: &Vec<Card>) -> u64 {
+    cards.iter().map(|card| card.point_value()).sum()
+}
+fn part_2(cards: &Vec<Card>) -> u64 {
+    let mut cards_won = [0; 11];
+    let mut total_cards = 0;
+    for card in cards {
+        let this_count = cards_won[0] + 1;
+        total_cards += this_count;
+        cards_won[0] = 0;
+        cards_won.rotate_left(1);
+        let counts = card.matching_cards();
+        for idx in 0..counts {
+            cards_won[idx] += this_count;
+        }
+    }
+
+    total_cards
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(4);
+}
@@ -0,0 +1,117 @@
+use crate::*;
+
+#[derive(Debug)]
+struct Almanac {
+    seeds: Vec<u64>,
+    /// The seed-to-soil, soil-to-fertilizer, ..., humidity-to-location maps composed together.
+    seed_to_location: RangeMap,
+    seed_to_location_min: MinImageQuery,
+}
+
+/// Errors are located within `input`, which `lines` are part of.
+fn parse_range_map(lines: &[&str], input: &str) -> Result<RangeMap, Error> {
+    let mut map = RangeMap::new();
+    for &line in lines {
+        let [dst_start, src_start, len] = line
+            .split_whitespace()
+            .map(|field| parse_field::<u64>(field, input))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| Error::new("expected three numbers").at(line, input))?;
+        let src_end = src_start
+            .checked_add(len)
+            .ok_or_else(|| Error::new("range too long").at(line, input))?;
+        map.insert(src_start..src_end, dst_start)
+            .map_err(|error| error.at(line, input))?;
+    }
+    Ok(map)
+}
+
+fn parse(input: &str) -> Result<Almanac, Error> {
+    let lines = crate::lines(input);
+
+    let mut groups = lines.split(|line| line.is_empty());
+
+    let seeds_line = groups
+        .next()
+        .and_then(|group| group.first())
+        .ok_or_else(|| Error::new("missing seeds"))?;
+    let seeds: Vec<u64> = seeds_line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| Error::new("expected \"seeds:\"").at(seeds_line, input))?
+        .split_whitespace()
+        .map(|field| parse_field(field, input))
+        .collect::<Result<_, _>>()?;
+
+    let layers = groups
+        .map(|group| parse_range_map(group.get(1..).unwrap_or_default(), input))
+        .collect::<Result<Vec<_>, _>>()?;
+    if layers.len() != 7 {
+        return Err(Error::new("expected seven maps"));
+    }
+
+    let seed_to_location = RangeMap::compose(&layers);
+    let seed_to_location_min = seed_to_location.min_image_query();
+    Ok(Almanac {
+        seeds,
+        seed_to_location,
+        seed_to_location_min,
+    })
+}
+
+fn part_1(almanac: &Almanac) -> Result<u64, Error> {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.seed_to_location.get(seed))
+        .min()
+        .ok_or_else(|| Error::new("no seeds"))
+}
+
+fn part_2(almanac: &Almanac) -> Result<u64, Error> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::new("expected seed ranges in pairs"));
+    }
+    let mut lowest = None;
+    for (&start, &len) in almanac.seeds.iter().tuples() {
+        let end = start
+            .checked_add(len)
+            .ok_or_else(|| Error::new(format!("seed range {start} {len} is too long")))?;
+        let range_lowest = almanac.seed_to_location_min.min(start..end);
+        lowest = lowest.into_iter().chain(range_lowest).min();
+    }
+    lowest.ok_or_else(|| Error::new("no seeds"))
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(5);
+}
+
+#[test]
+fn overflowing_seed_range() {
+    let examples = crate::examples::load(5).unwrap();
+    let (_, maps) = examples[0].input.split_once('\n').unwrap();
+    let input = format!("seeds: 18446744073709551610 10\n{maps}");
+    let almanac = parse(&input).unwrap();
+    assert_eq!(
+        part_2(&almanac).unwrap_err().to_string(),
+        "seed range 18446744073709551610 10 is too long"
+    );
+}
+
+#[test]
+fn odd_seed_count() {
+    let examples = crate::examples::load(5).unwrap();
+    let (_, maps) = examples[0].input.split_once('\n').unwrap();
+    let almanac = parse(&format!("seeds: 79 14 55\n{maps}")).unwrap();
+    assert_eq!(part_1(&almanac), Ok(43));
+    assert_eq!(
+        part_2(&almanac).unwrap_err().to_string(),
+        "expected seed ranges in pairs"
+    );
+}
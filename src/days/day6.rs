@@ -1,4 +1,4 @@
-use aoc_driver::*;
+use crate::{SeparateFunctions, Solution};
 use itertools::Itertools;
 
 /// TODO: use quadratic instead
@@ -55,21 +55,11 @@ fn part_2(input: &str) -> usize {
     number_of_ways_to_win(time, distance)
 }
 
-fn main() {
-    let session = std::fs::read_to_string(".session.txt").unwrap();
-    let session = session.trim();
-    if let Err(error) = aoc_magic!(session, 2023:6:2, part_2) {
-        eprintln!("Part 2 failed: {error:?}");
-    }
-    if let Err(error) = aoc_magic!(session, 2023:6:1, part_1) {
-        eprintln!("Part 1 failed: {error:?}");
-    }
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
 }
 
 #[test]
 fn example() {
-    let input = "Time:      7  15   30
-Distance:  9  40  200";
-    assert_eq!(part_1(input), 288);
-    assert_eq!(part_2(input), 71503);
+    crate::examples::check(6);
 }
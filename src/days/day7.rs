@@ -0,0 +1,397 @@
+use crate::{
+    days::Alternative, parse_field, try_parse_lines_located, Error, Params, Solution,
+    TryPreParsed,
+};
+use itertools::Itertools;
+
+/// Every card, from weakest to strongest by default.
+const DECK: &str = "23456789TJQKA";
+
+struct Hand {
+    cards: Vec<char>,
+    bid: usize,
+}
+
+/// The rules of a game of Camel Cards.
+#[derive(Debug, Clone)]
+struct Rules {
+    /// Every card, from weakest to strongest.
+    order: Vec<char>,
+    /// Cards which act like whichever card makes the hand's type strongest.
+    wildcards: Vec<char>,
+}
+
+/// How strong a hand is: its type, and then its cards' strengths in the order they were dealt.
+///
+/// A hand's type is how many of each card it has, most first, with its wildcards added to
+/// whichever card it has most of. Comparing these in order ranks the types as the puzzle does:
+/// five of a kind (`[5]`) beats four of a kind (`[4, 1]`), which beats a full house (`[3, 2]`), and
+/// so on down to high card (`[1, 1, 1, 1, 1]`), and likewise for hands of any other size.
+type Strength = (Vec<usize>, Vec<usize>);
+
+impl Rules {
+    fn new(order: &[char], wildcards: &[char]) -> Self {
+        Self {
+            order: order.to_vec(),
+            wildcards: wildcards.to_vec(),
+        }
+    }
+
+    /// Like [`Rules::new`], but with `wildcards` moved to the weak end of `order`, as jokers are.
+    fn with_jokers(order: &[char], jokers: &[char]) -> Self {
+        let (wild, natural): (Vec<char>, Vec<char>) =
+            order.iter().partition(|card| jokers.contains(card));
+        Self::new(&[wild, natural].concat(), jokers)
+    }
+
+    fn strength(&self, cards: &[char]) -> Strength {
+        let mut counts = cards
+            .iter()
+            .filter(|card| !self.wildcards.contains(card))
+            .counts()
+            .into_values()
+            .sorted_unstable_by(|a, b| b.cmp(a))
+            .collect_vec();
+        let wildcards = cards.len() - counts.iter().sum::<usize>();
+        match counts.first_mut() {
+            Some(most) => *most += wildcards,
+            None => counts.push(wildcards),
+        }
+        let strengths = cards
+            .iter()
+            .map(|card| self.order.iter().position(|c| c == card).unwrap())
+            .collect();
+        (counts, strengths)
+    }
+
+    /// Like [`Rules::strength`], but by trying every other card in place of the wildcards.
+    fn strength_by_substitution(&self, cards: &[char]) -> Strength {
+        let natural = Rules::new(&self.order, &[]);
+        let counts = self
+            .order
+            .iter()
+            .copied()
+            .filter(|card| !self.wildcards.contains(card))
+            .map(|substitute| {
+                let cards = cards
+                    .iter()
+                    .map(|&card| {
+                        if self.wildcards.contains(&card) {
+                            substitute
+                        } else {
+                            card
+                        }
+                    })
+                    .collect_vec();
+                natural.strength(&cards).0
+            })
+            .max()
+            .unwrap_or_else(|| vec![cards.len()]);
+        (counts, self.strength(cards).1)
+    }
+}
+
+fn rank(card: char) -> u8 {
+    match card {
+        '2'..='9' => card as u8 - b'0',
+        'T' => 10,
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => unreachable!(),
+    }
+}
+
+fn rank2(card: char) -> u8 {
+    match card {
+        'J' => 1,
+        '2'..='9' => card as u8 - b'0',
+        'T' => 10,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => unreachable!(),
+    }
+}
+
+/// The puzzle's part 1 ranking, hard-coded for five-card hands: the hand's type (7 for five of a
+/// kind down to 1 for high card), then its cards' ranks.
+fn categorize(cards: &[char]) -> (u32, [u8; 5]) {
+    let cards: [char; 5] = cards.try_into().unwrap();
+    let ranks = cards.map(rank);
+    let mut sorted_cards = cards;
+    sorted_cards.sort_unstable();
+    if sorted_cards[4] == sorted_cards[0] {
+        // five of a kind
+        (7, ranks)
+    } else if sorted_cards[0] == sorted_cards[3] || sorted_cards[1] == sorted_cards[4] {
+        // four of a kind
+        (6, ranks)
+    } else if sorted_cards[2] == sorted_cards[4]
+        || sorted_cards[2] == sorted_cards[0]
+        || sorted_cards[1] == sorted_cards[3]
+    {
+        // three of a kind or full house
+        if (sorted_cards[0] == sorted_cards[2] && sorted_cards[3] == sorted_cards[4])
+            || (sorted_cards[0] == sorted_cards[1] && sorted_cards[2] == sorted_cards[4])
+        {
+            // full house
+            (5, ranks)
+        } else {
+            // three of a kind
+            (4, ranks)
+        }
+    } else if (sorted_cards[0] == sorted_cards[1] || sorted_cards[1] == sorted_cards[2])
+        && (sorted_cards[2] == sorted_cards[3] || sorted_cards[3] == sorted_cards[4])
+    {
+        // two pair
+        (3, ranks)
+    } else if sorted_cards[0] == sorted_cards[1]
+        || sorted_cards[1] == sorted_cards[2]
+        || sorted_cards[2] == sorted_cards[3]
+        || sorted_cards[3] == sorted_cards[4]
+    {
+        // one pair
+        (2, ranks)
+    } else {
+        // high card
+        (1, ranks)
+    }
+}
+
+fn can_be_full_house(jokers: usize, sorted_non_joker_cards: &[char]) -> bool {
+    jokers >= 3
+        || (jokers == 2
+            && (sorted_non_joker_cards[0] == sorted_non_joker_cards[1]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[2]))
+        || (jokers == 1
+            && ((sorted_non_joker_cards[0] == sorted_non_joker_cards[1]
+                && sorted_non_joker_cards[2] == sorted_non_joker_cards[3])
+                || sorted_non_joker_cards[0] == sorted_non_joker_cards[2]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[3]))
+}
+
+fn can_be_two_pair(jokers: usize, sorted_non_joker_cards: &[char]) -> bool {
+    jokers >= 2
+        || (jokers == 1
+            && (sorted_non_joker_cards[0] == sorted_non_joker_cards[1]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[2]
+                || sorted_non_joker_cards[2] == sorted_non_joker_cards[3]))
+}
+
+/// Like [`categorize`], but for part 2, where `J`s are jokers.
+fn categorize2(cards: &[char]) -> (u32, [u8; 5]) {
+    if !cards.contains(&'J') {
+        return categorize(cards);
+    }
+    // We know there's a joker
+    let cards: [char; 5] = cards.try_into().unwrap();
+    let ranks = cards.map(rank2);
+    let jokers = cards.iter().filter(|&&card| card == 'J').count();
+    let mut sorted_non_joker_cards = cards
+        .iter()
+        .copied()
+        .filter(|&card| card != 'J')
+        .collect_vec();
+    sorted_non_joker_cards.sort_unstable();
+    if jokers >= 4
+        || (jokers == 3 && sorted_non_joker_cards[0] == sorted_non_joker_cards[1])
+        || (jokers == 2 && sorted_non_joker_cards[0] == sorted_non_joker_cards[2])
+        || (jokers == 1 && sorted_non_joker_cards[0] == sorted_non_joker_cards[3])
+    {
+        // five of a kind
+        (7, ranks)
+    } else if jokers == 3
+        || (jokers == 2
+            && (sorted_non_joker_cards[0] == sorted_non_joker_cards[1]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[2]))
+        || (jokers == 1
+            && (sorted_non_joker_cards[0] == sorted_non_joker_cards[2]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[3]))
+    {
+        // four of a kind
+        (6, ranks)
+    } else if jokers == 2
+        || (jokers == 1
+            && (sorted_non_joker_cards[0] == sorted_non_joker_cards[1]
+                || sorted_non_joker_cards[1] == sorted_non_joker_cards[2]
+                || sorted_non_joker_cards[2] == sorted_non_joker_cards[3]))
+    {
+        // three of a kind or full house
+        if can_be_full_house(jokers, &sorted_non_joker_cards) {
+            // full house
+            (5, ranks)
+        } else {
+            // three of a kind
+            (4, ranks)
+        }
+    } else if can_be_two_pair(jokers, &sorted_non_joker_cards) {
+        // two pair
+        (3, ranks)
+    } else {
+        // one pair (we know we have at least this since there's a joker)
+        (2, ranks)
+    }
+}
+
+/// Runs one part with [`categorize`] or [`categorize2`], which only know the puzzle's own rules.
+fn classic_winnings(
+    input: &str,
+    params: &Params,
+    categorize: fn(&[char]) -> (u32, [u8; 5]),
+) -> Result<usize, Error> {
+    for name in ["hand_size", "order", "jokers"] {
+        if params.get::<String>(name)?.is_some() {
+            return Err(Error::new(format!(
+                "the original classifier only knows the puzzle's rules, so cannot take the {name} parameter"
+            )));
+        }
+    }
+    Ok(total_winnings(&parse(input, 5)?, categorize))
+}
+
+/// Each hand must have `hand_size` cards.
+fn parse(input: &str, hand_size: usize) -> Result<Vec<Hand>, Error> {
+    try_parse_lines_located(input, |line| {
+        let (cards, bid) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::new("expected a hand and a bid").at(line, line))?;
+        if let Some(card) = cards.chars().find(|&card| !DECK.contains(card)) {
+            return Err(Error::new(format!("unknown card {card:?}")).at(cards, line));
+        }
+        if cards.chars().count() != hand_size {
+            return Err(Error::new(format!("expected {hand_size} cards")).at(cards, line));
+        }
+        Ok(Hand {
+            cards: cards.chars().collect(),
+            bid: parse_field(bid.trim(), line)?,
+        })
+    })
+}
+
+fn total_winnings<S: Ord>(hands: &[Hand], strength: impl Fn(&[char]) -> S) -> usize {
+    hands
+        .iter()
+        .sorted_by_cached_key(|hand| strength(&hand.cards))
+        .enumerate()
+        .map(|(idx, hand)| (idx + 1) * hand.bid)
+        .sum()
+}
+
+fn winnings(hands: &[Hand], rules: &Rules) -> usize {
+    total_winnings(hands, |cards| rules.strength(cards))
+}
+
+/// The parameters `hand_size` (5 by default), `order` (every card from weakest to strongest,
+/// `23456789TJQKA` by default) and `jokers` (the cards which are wild in part 2, and weakest,
+/// `J` by default). Returns the hand size and each part's rules.
+fn parse_params(params: &Params) -> Result<(usize, [Rules; 2]), Error> {
+    let hand_size = params.get("hand_size")?.unwrap_or(5);
+    let order: String = params.get("order")?.unwrap_or_else(|| DECK.to_string());
+    if !order.chars().sorted().eq(DECK.chars().sorted()) {
+        return Err(Error::new(format!(
+            "the card order {order:?} is not a permutation of {DECK:?}"
+        )));
+    }
+    let jokers: String = params.get("jokers")?.unwrap_or_else(|| "J".to_string());
+    if let Some(card) = jokers.chars().find(|&card| !DECK.contains(card)) {
+        return Err(Error::new(format!("unknown joker card {card:?}")));
+    }
+    let order = order.chars().collect_vec();
+    let jokers = jokers.chars().collect_vec();
+    Ok((
+        hand_size,
+        [Rules::new(&order, &[]), Rules::with_jokers(&order, &jokers)],
+    ))
+}
+
+pub const ALTERNATIVES: &[Alternative] = &[
+    Alternative {
+        part: 1,
+        name: "original classifier",
+        run: |input, params| Ok(classic_winnings(input, params, categorize)?.to_string()),
+    },
+    Alternative {
+        part: 2,
+        name: "original classifier",
+        run: |input, params| Ok(classic_winnings(input, params, categorize2)?.to_string()),
+    },
+    Alternative {
+        part: 2,
+        name: "substituting jokers",
+        run: |input, params| {
+            let (hand_size, [_, rules]) = parse_params(params)?;
+            let hands = parse(input, hand_size)?;
+            Ok(total_winnings(&hands, |cards| rules.strength_by_substitution(cards)).to_string())
+        },
+    },
+];
+
+/// The `hand_size` parameter overrides how many cards each hand has, the `order` parameter how
+/// strong each card is, and the `jokers` parameter which cards are wild in part 2.
+pub fn solution(params: &Params) -> Result<Box<dyn Solution>, Error> {
+    let (hand_size, [rules_1, rules_2]) = parse_params(params)?;
+    Ok(Box::new(TryPreParsed::new(
+        move |input: &str| parse(input, hand_size),
+        move |hands: &Vec<Hand>| winnings(hands, &rules_1),
+        move |hands: &Vec<Hand>| winnings(hands, &rules_2),
+    )))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(7);
+}
+
+#[test]
+fn strength() {
+    let deck = DECK.chars().collect_vec();
+    let rules = Rules::with_jokers(&deck, &['J', 'Q']);
+    assert_eq!(rules.order.iter().collect::<String>(), "JQ23456789TKA");
+    for (hand, counts) in [
+        ("23456", vec![1, 1, 1, 1, 1]),
+        ("2J456", vec![2, 1, 1, 1]),
+        ("2JQ45", vec![3, 1, 1]),
+        ("22Q44", vec![3, 2]),
+        ("JQJQJ", vec![5]),
+        ("2233JK", vec![3, 2, 1]),
+        ("JJQQ2A", vec![5, 1]),
+    ] {
+        let cards = hand.chars().collect_vec();
+        assert_eq!(rules.strength(&cards).0, counts, "{hand}");
+        assert_eq!(
+            rules.strength_by_substitution(&cards),
+            rules.strength(&cards),
+            "{hand}"
+        );
+    }
+}
+
+#[test]
+fn card_order() {
+    let hands = parse("A2345 1\n2345A 2\nJJ234 3\n", 5).unwrap();
+    let reversed = DECK.chars().rev().collect_vec();
+    // The aces now rank lowest, so the first hand is weakest.
+    assert_eq!(winnings(&hands, &Rules::new(&reversed, &[])), 1 + 2 * 2 + 3 * 3);
+    // The jokers make a three of a kind, and are moved below the aces.
+    let rules = Rules::with_jokers(&reversed, &['J']);
+    assert_eq!(rules.order.iter().collect::<String>(), "JAKQT98765432");
+    assert_eq!(winnings(&hands, &rules), 1 + 2 * 2 + 3 * 3);
+
+    let params = Params::new().with("order", "23456789TJQK");
+    assert!(parse_params(&params).is_err());
+}
+
+#[test]
+fn alternatives() {
+    let examples = crate::examples::load(7).unwrap();
+    let params = Params::new();
+    for alternative in ALTERNATIVES {
+        let answer = (alternative.run)(&examples[0].input, &params).unwrap();
+        let expected = ["6440", "5905"][alternative.part as usize - 1];
+        assert_eq!(answer, expected, "{}", alternative.name);
+    }
+    let params = Params::new().with("jokers", "Q");
+    assert!((ALTERNATIVES[1].run)(&examples[0].input, &params).is_err());
+}
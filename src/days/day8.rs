@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::{
+    diagram::{Diagram, Shape},
+    Error, SeparateFunctions, Solution,
+};
+use itertools::Itertools;
+use num_integer::Integer;
+
+#[derive(Debug, Clone, Copy)]
+#[repr(u8)]
+enum Direction {
+    Left = 0,
+    Right = 1,
+}
+
+type Graph<'a> = HashMap<&'a str, [&'a str; 2]>;
+
+fn parse(input: &str) -> Result<(Vec<Direction>, Graph<'_>), Error> {
+    let (directions, network) = input
+        .split_once('\n')
+        .ok_or_else(|| Error::new("expected the directions, then the network"))?;
+    let directions = directions.trim();
+    let directions = directions
+        .char_indices()
+        .map(|(idx, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(Error::new(format!("unknown direction {c:?}"))
+                .at(&directions[idx..idx + c.len_utf8()], input)),
+        })
+        .collect::<Result<_, _>>()?;
+    let map = network
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split_once(" = ")
+                .and_then(|(src, dsts)| {
+                    let (left, right) = dsts.strip_prefix('(')?.strip_suffix(')')?.split_once(", ")?;
+                    Some((src, [left, right]))
+                })
+                .ok_or_else(|| Error::new("expected a node like `AAA = (BBB, CCC)`").at(line, input))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((directions, map))
+}
+
+/// Like [`parse`], but panics with the located error, for the part functions.
+fn parse_or_panic(input: &str) -> (Vec<Direction>, Graph<'_>) {
+    parse(input).unwrap_or_else(|error| panic!("{}", error.locate(input)))
+}
+
+fn part_1(input: &str) -> usize {
+    let (directions, map) = parse_or_panic(input);
+    let mut node = "AAA";
+    for (idx, direction) in directions.iter().copied().cycle().enumerate() {
+        if node == "ZZZ" {
+            return idx;
+        }
+        node = map[node][direction as usize]
+    }
+    unreachable!()
+}
+
+fn part_2(input: &str) -> usize {
+    let (directions, map) = parse_or_panic(input);
+    let starting_nodes = map
+        .iter()
+        .flat_map(|(&src, &[dst1, dst2])| [src, dst1, dst2])
+        .filter(|node| node.ends_with('A'))
+        .collect_vec();
+    let lengths = starting_nodes
+        .iter()
+        .copied()
+        .map(|mut node| {
+            for (idx, direction) in directions.iter().copied().cycle().enumerate() {
+                if node.ends_with('Z') {
+                    return idx;
+                }
+                node = map[node][direction as usize]
+            }
+            unreachable!()
+        })
+        .collect_vec();
+    lengths.into_iter().reduce(|a, b| a.lcm(&b)).unwrap()
+}
+
+/// The network, with the starting nodes (ending in `A`) boxed and the ending nodes (ending in
+/// `Z`) double-circled.
+pub fn graphs(input: &str) -> Result<Vec<Diagram>, Error> {
+    let (_, map) = parse(input)?;
+    let mut diagram = Diagram::new("network");
+    for (&node, &[left, right]) in map.iter().sorted() {
+        if node.ends_with('A') {
+            diagram.node(node).shape(Shape::Box);
+        } else if node.ends_with('Z') {
+            diagram.node(node).shape(Shape::DoubleCircle);
+        }
+        diagram.edge(node, left).label("L");
+        diagram.edge(node, right).label("R");
+    }
+    Ok(vec![diagram])
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(SeparateFunctions::new(part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(8);
+}
+
+#[test]
+fn parse_error() {
+    let input = "LRX\n\nAAA = (BBB, CCC)\n";
+    let error = parse(input).unwrap_err().locate(input);
+    assert_eq!(error.to_string(), "line 1, column 3: unknown direction 'X'");
+    let input = "LR\n\nAAA = (BBB, CCC)\nBBB = CCC\n";
+    let error = parse(input).unwrap_err().locate(input);
+    assert_eq!(
+        error.to_string(),
+        "line 4, column 1: expected a node like `AAA = (BBB, CCC)`"
+    );
+}
@@ -0,0 +1,45 @@
+use crate::*;
+use itertools::Itertools;
+
+fn predict_next(sequence: &[isize]) -> isize {
+    if (1..sequence.len()).all(|idx| sequence[idx] == sequence[0]) {
+        return sequence[0];
+    }
+    let last = *sequence.last().unwrap();
+    let diff_sequence = sequence.windows(2).map(|win| win[1] - win[0]).collect_vec();
+    last + predict_next(&diff_sequence)
+}
+
+fn predict_prev(sequence: &[isize]) -> isize {
+    if (1..sequence.len()).all(|idx| sequence[idx] == sequence[0]) {
+        return sequence[0];
+    }
+    let first = *sequence.first().unwrap();
+    let diff_sequence = sequence.windows(2).map(|win| win[1] - win[0]).collect_vec();
+    first - predict_prev(&diff_sequence)
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<isize>>, Error> {
+    try_parse_lines_located(input, |line| {
+        line.split_whitespace()
+            .map(|field| parse_field(field, line))
+            .collect::<Result<_, _>>()
+    })
+}
+
+fn part_1(seqs: &Vec<Vec<isize>>) -> isize {
+    seqs.iter().map(|seq| predict_next(seq)).sum()
+}
+
+fn part_2(seqs: &Vec<Vec<isize>>) -> isize {
+    seqs.iter().map(|seq| predict_prev(seq)).sum()
+}
+
+pub fn solution() -> Box<dyn Solution> {
+    Box::new(TryPreParsed::new(parse, part_1, part_2))
+}
+
+#[test]
+fn example() {
+    crate::examples::check(9);
+}
@@ -0,0 +1,192 @@
+//! Every day's solutions, registered by day number.
+
+use crate::{Diagram, Error, Params, Solution};
+
+/// Constructs a day's solution, configured by the given parameters.
+pub type Constructor = fn(&Params) -> Result<Box<dyn Solution>, Error>;
+
+/// Days whose solutions take parameters are registered as `N => dayN(params)`, and their
+/// `solution` function takes `&Params`. Other days' `solution` functions take no arguments.
+///
+/// A day can also list what else it provides in brackets after that, e.g. `N => dayN [graphs]`
+/// for a day with a `graphs` function drawing its input, or `[alternatives]` for a day with an
+/// `ALTERNATIVES` list of other implementations of its parts, or `[report]` for a day with a
+/// `report` function describing how it solves its input.
+macro_rules! days {
+    (@constructor $module:ident) => {
+        |_| Ok($module::solution())
+    };
+    (@constructor $module:ident params) => {
+        $module::solution
+    };
+    (@graphs $module:ident) => {
+        None
+    };
+    (@graphs $module:ident graphs $($rest:ident)*) => {
+        Some($module::graphs as GraphsFn)
+    };
+    (@graphs $module:ident $other:ident $($rest:ident)*) => {
+        days!(@graphs $module $($rest)*)
+    };
+    (@alternatives $module:ident) => {
+        &[]
+    };
+    (@alternatives $module:ident alternatives $($rest:ident)*) => {
+        $module::ALTERNATIVES
+    };
+    (@alternatives $module:ident $other:ident $($rest:ident)*) => {
+        days!(@alternatives $module $($rest)*)
+    };
+    (@report $module:ident) => {
+        None
+    };
+    (@report $module:ident report $($rest:ident)*) => {
+        Some($module::report as ReportFn)
+    };
+    (@report $module:ident $other:ident $($rest:ident)*) => {
+        days!(@report $module $($rest)*)
+    };
+    ($($day:literal => $module:ident $(($params:ident))? $([$($extra:ident),*])?,)*) => {
+        $(pub mod $module;)*
+
+        /// The day number and solution constructor of every day, in order.
+        pub const DAYS: &[(u32, Constructor)] = &[$(($day, days!(@constructor $module $($params)?))),*];
+
+        /// The function drawing each day's input, if it has one.
+        const GRAPHS: &[(u32, Option<GraphsFn>)] = &[$(($day, days!(@graphs $module $($($extra)*)?))),*];
+
+        /// The alternative implementations of each day's parts.
+        const ALTERNATIVES: &[(u32, &[Alternative])] = &[$(($day, days!(@alternatives $module $($($extra)*)?))),*];
+
+        /// The function describing each day's workings, if it has one.
+        const REPORTS: &[(u32, Option<ReportFn>)] = &[$(($day, days!(@report $module $($($extra)*)?))),*];
+    };
+}
+
+days! {
+    1 => day1 [alternatives],
+    2 => day2,
+    3 => day3,
+    4 => day4,
+    5 => day5,
+    6 => day6,
+    7 => day7(params) [alternatives],
+    8 => day8 [graphs],
+    9 => day9,
+    10 => day10 [alternatives],
+    11 => day11(params),
+    12 => day12(params),
+    13 => day13,
+    14 => day14(params),
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20 [graphs],
+    21 => day21(params) [alternatives],
+    22 => day22,
+    23 => day23 [graphs],
+    24 => day24(params) [alternatives, report],
+    // `cargo newday` inserts new days above this line.
+}
+
+/// Draws a day's puzzle input as diagrams, for `cargo aoc --graphs`.
+pub type GraphsFn = fn(&str) -> Result<Vec<Diagram>, Error>;
+
+/// The function drawing the given day's input, or `None` if that day has none.
+pub fn graphs(day: u32) -> Option<GraphsFn> {
+    GRAPHS
+        .iter()
+        .find(|&&(registered, _)| registered == day)
+        .and_then(|&(_, graphs)| graphs)
+}
+
+/// Describes how a day solves its puzzle input, configured by the same parameters as its solution,
+/// for `cargo aoc --report`.
+pub type ReportFn = fn(&str, &Params) -> Result<String, Error>;
+
+/// The function describing the given day's workings, or `None` if that day has none.
+pub fn report(day: u32) -> Option<ReportFn> {
+    REPORTS
+        .iter()
+        .find(|&&(registered, _)| registered == day)
+        .and_then(|&(_, report)| report)
+}
+
+/// Another implementation of one part of a day, for checking the main implementation against
+/// with `cargo aoc --cross-check`.
+#[derive(Debug, Clone, Copy)]
+pub struct Alternative {
+    pub part: u32,
+    /// How this implementation differs from the main one.
+    pub name: &'static str,
+    /// Solves the part from the raw input, configured by the same parameters as the main
+    /// implementation.
+    pub run: fn(&str, &Params) -> Result<String, Error>,
+}
+
+/// The alternative implementations of the given day's parts, if any.
+pub fn alternatives(day: u32) -> &'static [Alternative] {
+    ALTERNATIVES
+        .iter()
+        .find(|&&(registered, _)| registered == day)
+        .map_or(&[], |&(_, alternatives)| alternatives)
+}
+
+/// The constructor of the given day's solution, or `None` if there is no solution for that day.
+pub fn find(day: u32) -> Option<Constructor> {
+    DAYS.iter()
+        .find(|&&(registered, _)| registered == day)
+        .map(|&(_, solution)| solution)
+}
+
+/// A fresh instance of the given day's solution, configured by `params`, or `None` if there is no
+/// solution for that day.
+///
+/// Fails if a parameter has an invalid value. Parameters which that day does not read are ignored,
+/// so the same parameters can configure several days; check them with [`check_params`].
+pub fn solution(day: u32, params: &Params) -> Option<Result<Box<dyn Solution>, Error>> {
+    find(day).map(|solution| solution(params))
+}
+
+/// Fails if a parameter is not read by any of the given days, which is probably a typo, or if a
+/// day rejects a parameter's value.
+pub fn check_params(days: &[u32], params: &Params) -> Result<(), Error> {
+    params.reset_used();
+    for &day in days {
+        // A day stops reading parameters at the first invalid one, so the parameters after it
+        // would wrongly look unknown.
+        if let Some(Err(error)) = solution(day, params) {
+            return Err(error.context(&format!("day {day}")));
+        }
+    }
+    params.check_used()
+}
+
+/// The highest day with a solution.
+pub fn latest() -> u32 {
+    DAYS.iter().map(|&(day, _)| day).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_params() {
+        let params = Params::new().with("steps", 10);
+        assert!(solution(1, &params).unwrap().is_ok());
+        assert_eq!(check_params(&[1, 21], &params), Ok(()));
+        assert_eq!(
+            check_params(&[1, 2], &params).unwrap_err().to_string(),
+            "unknown parameter steps"
+        );
+
+        let params = Params::new().with("order", "bad").with("jokers", "Q");
+        assert_eq!(
+            check_params(&[7], &params).unwrap_err().to_string(),
+            "day 7: the card order \"bad\" is not a permutation of \"23456789TJQKA\""
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, fmt::Write};
+
+/// A graph to draw, which can be exported to Graphviz DOT or Mermaid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagram {
+    /// Used in the exported file names, so should be short and free of spaces.
+    pub name: String,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Shape {
+    #[default]
+    Ellipse,
+    Box,
+    Circle,
+    DoubleCircle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub id: String,
+    /// Shown instead of the ID, if set.
+    pub label: Option<String>,
+    pub shape: Shape,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub label: Option<String>,
+    /// Whether to draw an arrowhead at `to`.
+    pub directed: bool,
+    pub dashed: bool,
+}
+
+impl Node {
+    pub fn label(&mut self, label: impl ToString) -> &mut Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn shape(&mut self, shape: Shape) -> &mut Self {
+        self.shape = shape;
+        self
+    }
+}
+
+impl Edge {
+    pub fn label(&mut self, label: impl ToString) -> &mut Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn undirected(&mut self) -> &mut Self {
+        self.directed = false;
+        self
+    }
+
+    pub fn dashed(&mut self) -> &mut Self {
+        self.dashed = true;
+        self
+    }
+}
+
+/// Quote a string for DOT.
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Quote a string for Mermaid, which escapes characters as HTML entities.
+fn mermaid_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "#quot;"))
+}
+
+impl Diagram {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Add a node, which is drawn with its ID as its label until it is given another.
+    pub fn node(&mut self, id: impl ToString) -> &mut Node {
+        self.nodes.push(Node {
+            id: id.to_string(),
+            label: None,
+            shape: Shape::default(),
+        });
+        self.nodes.last_mut().unwrap()
+    }
+
+    /// Add a directed edge. Nodes which were not added with [`Diagram::node`] are drawn with
+    /// their IDs as their labels.
+    pub fn edge(&mut self, from: impl ToString, to: impl ToString) -> &mut Edge {
+        self.edges.push(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            label: None,
+            directed: true,
+            dashed: false,
+        });
+        self.edges.last_mut().unwrap()
+    }
+
+    /// The diagram in Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph {} {{\n", dot_quote(&self.name));
+        for node in &self.nodes {
+            let mut attributes = vec![];
+            if let Some(label) = &node.label {
+                attributes.push(format!("label={}", dot_quote(label)));
+            }
+            match node.shape {
+                Shape::Ellipse => {}
+                Shape::Box => attributes.push("shape=box".to_string()),
+                Shape::Circle => attributes.push("shape=circle".to_string()),
+                Shape::DoubleCircle => attributes.push("shape=doublecircle".to_string()),
+            }
+            write!(dot, "    {}", dot_quote(&node.id)).unwrap();
+            if !attributes.is_empty() {
+                write!(dot, " [{}]", attributes.join(", ")).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        for edge in &self.edges {
+            let mut attributes = vec![];
+            if let Some(label) = &edge.label {
+                attributes.push(format!("label={}", dot_quote(label)));
+            }
+            if !edge.directed {
+                attributes.push("dir=none".to_string());
+            }
+            if edge.dashed {
+                attributes.push("style=dashed".to_string());
+            }
+            write!(
+                dot,
+                "    {} -> {}",
+                dot_quote(&edge.from),
+                dot_quote(&edge.to)
+            )
+            .unwrap();
+            if !attributes.is_empty() {
+                write!(dot, " [{}]", attributes.join(", ")).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The diagram as a Mermaid flowchart.
+    ///
+    /// Mermaid is fussy about which characters node IDs can contain, so nodes are renamed `n0`,
+    /// `n1`, ... and labelled with their original IDs.
+    pub fn to_mermaid(&self) -> String {
+        let mut ids: HashMap<&str, usize> = HashMap::new();
+        let mut nodes = vec![];
+        let ids_of_edges = self.edges.iter().flat_map(|edge| [&edge.from, &edge.to]);
+        for (id, node) in self
+            .nodes
+            .iter()
+            .map(|node| (&node.id, Some(node)))
+            .chain(ids_of_edges.map(|id| (id, None)))
+        {
+            if !ids.contains_key(id.as_str()) {
+                ids.insert(id, nodes.len());
+                nodes.push((id, node));
+            }
+        }
+
+        let mut mermaid = String::from("flowchart TD\n");
+        for (index, (id, node)) in nodes.iter().enumerate() {
+            let label = mermaid_quote(node.and_then(|node| node.label.as_ref()).unwrap_or(id));
+            let shape = node.map_or(Shape::default(), |node| node.shape);
+            let (open, close) = match shape {
+                Shape::Ellipse => ("([", "])"),
+                Shape::Box => ("[", "]"),
+                Shape::Circle => ("((", "))"),
+                Shape::DoubleCircle => ("(((", ")))"),
+            };
+            writeln!(mermaid, "    n{index}{open}{label}{close}").unwrap();
+        }
+        for edge in &self.edges {
+            let arrow = match (edge.directed, edge.dashed) {
+                (true, false) => "-->",
+                (false, false) => "---",
+                (true, true) => "-.->",
+                (false, true) => "-.-",
+            };
+            let label = edge
+                .label
+                .as_ref()
+                .map(|label| format!("|{}|", mermaid_quote(label)))
+                .unwrap_or_default();
+            writeln!(
+                mermaid,
+                "    n{} {arrow}{label} n{}",
+                ids[edge.from.as_str()],
+                ids[edge.to.as_str()]
+            )
+            .unwrap();
+        }
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export() {
+        let mut diagram = Diagram::new("example");
+        diagram.node("a").shape(Shape::Box);
+        diagram
+            .node("b")
+            .label("say \"hi\"")
+            .shape(Shape::DoubleCircle);
+        diagram.edge("a", "b").label(3);
+        diagram.edge("b", "c").undirected().dashed();
+
+        assert_eq!(
+            diagram.to_dot(),
+            "digraph \"example\" {\n\
+             \x20   \"a\" [shape=box];\n\
+             \x20   \"b\" [label=\"say \\\"hi\\\"\", shape=doublecircle];\n\
+             \x20   \"a\" -> \"b\" [label=\"3\"];\n\
+             \x20   \"b\" -> \"c\" [dir=none, style=dashed];\n\
+             }\n"
+        );
+        assert_eq!(
+            diagram.to_mermaid(),
+            "flowchart TD\n\
+             \x20   n0[\"a\"]\n\
+             \x20   n1(((\"say #quot;hi#quot;\")))\n\
+             \x20   n2([\"c\"])\n\
+             \x20   n0 -->|\"3\"| n1\n\
+             \x20   n1 -.- n2\n"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::{any::Any, fmt, str::FromStr};
+
+/// An error from parsing or solving a puzzle, optionally pointing at the
+/// (1-based) line and column of the input that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    /// Byte offset of the offending part of the text being parsed, resolved into a line and
+    /// column by [`Error::locate`].
+    offset: Option<usize>,
+}
+
+impl Error {
+    pub fn new(message: impl fmt::Display) -> Self {
+        Self {
+            message: message.to_string(),
+            line: None,
+            column: None,
+            offset: None,
+        }
+    }
+
+    /// Record that this error was caused by `part`, a substring of `within`, which is the text being
+    /// parsed. The location is kept as an offset into `within`.
+    ///
+    /// Does nothing if the error already has a location, or if `part` is not within `within`.
+    pub fn at(mut self, part: &str, within: &str) -> Self {
+        let start = within.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if !self.has_location() && offset <= within.len() && part.len() <= within.len() - offset {
+            self.offset = Some(offset);
+        }
+        self
+    }
+
+    fn has_location(&self) -> bool {
+        self.offset.is_some() || self.line.is_some()
+    }
+
+    /// Record that this error was caused by the given (1-based) line of the input.
+    ///
+    /// Does nothing if the error already has a location.
+    pub fn with_line(mut self, line: usize) -> Self {
+        if !self.has_location() {
+            self.line = Some(line);
+        }
+        self
+    }
+
+    /// Resolve a location recorded with [`Error::at`] into a line and column of `input`, which must
+    /// be the text the error was raised on.
+    pub fn locate(mut self, input: &str) -> Self {
+        if let Some(before) = self.offset.and_then(|offset| input.get(..offset)) {
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+            self.offset = None;
+        }
+        self
+    }
+
+    /// Shift a location recorded with [`Error::at`] on a substring of some text, which starts
+    /// `offset` bytes into that text, to be a location in that text.
+    fn shift(mut self, offset: usize) -> Self {
+        self.offset = self.offset.map(|start| start + offset);
+        self
+    }
+
+    /// Prefix the message with what failed, keeping the location.
+    pub fn context(mut self, what: &str) -> Self {
+        self.message = format!("{what}: {}", self.message);
+        self
+    }
+
+    /// Convert a panic payload (from [`std::panic::catch_unwind`]) into an error.
+    pub fn from_panic(what: &str, payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+        match message {
+            Some(message) => Self::new(format!("{what} panicked: {message}")),
+            None => Self::new(format!("{what} panicked")),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+/// Parse a field of `within`, the text being parsed, recording its location if it is invalid.
+pub fn parse_field<T: FromStr>(field: &str, within: &str) -> Result<T, Error>
+where
+    T::Err: fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|err| Error::new(format!("invalid value {field:?}: {err}")).at(field, within))
+}
+
+/// Parse AoC input format into a vector of the output of a given function called
+/// on each indvidual input line (trimmed). If any call to the function fails, the
+/// whole parsing fails, and the error is given the line and column it came from.
+///
+/// Errors from `f` should be located with [`Error::at`] within the line `f` was given.
+pub fn try_parse_lines_located<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let mut line_start = 0;
+    input
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(idx, line)| {
+            let trimmed_start = line_start + (line.len() - line.trim_start().len());
+            line_start += line.len();
+            f(line.trim())
+                .map_err(|error| error.shift(trimmed_start).locate(input).with_line(idx + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_field, try_parse_lines_located, Error};
+
+    #[test]
+    fn locate() {
+        let input = "1 2\n3 x 4\n";
+        let error = try_parse_lines_located(input, |line| {
+            line.split_whitespace()
+                .map(|field| parse_field::<u32>(field, line))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid value \"x\": invalid digit found in string"
+        );
+
+        let error = try_parse_lines_located(input, |line| match line {
+            "3 x 4" => Err(Error::new("bad line")),
+            _ => Ok(()),
+        })
+        .unwrap_err();
+        assert_eq!(error.to_string(), "line 2: bad line");
+
+        let input = "1 2\r\n  3 x\r\n";
+        let error = try_parse_lines_located(input, |line| {
+            line.split_whitespace()
+                .map(|field| parse_field::<u32>(field, line))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(5)));
+
+        // A part of some other string has no location in the input.
+        let other = String::from("3 x");
+        let error = Error::new("bad").at(&other[2..], input).locate(input);
+        assert_eq!((error.line(), error.column()), (None, None));
+    }
+}
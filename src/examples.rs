@@ -0,0 +1,199 @@
+//! Example inputs from the puzzle descriptions, with their expected answers.
+//!
+//! Each example for day N is a file `examples/dayN/NAME.txt` next to a sidecar file
+//! `examples/dayN/NAME.json`, which holds either one case or a list of cases to run the example
+//! with, like `{"params": {"steps": 6}, "part_1": 16}`. Parts without an expected answer are not
+//! run, and the parameters are passed to the day's solution like `cargo aoc --param` does.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{days, Error, Params};
+
+/// Where the examples for the given day are stored.
+pub fn examples_dir(day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day{day}"))
+}
+
+/// An answer or parameter value, which may be written in the sidecar as a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(serde_json::Number),
+    String(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::String(string) => f.write_str(string),
+        }
+    }
+}
+
+/// One way to run an example: with which parameters, and what each part should answer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Case {
+    #[serde(default)]
+    pub params: BTreeMap<String, Value>,
+    pub part_1: Option<Value>,
+    pub part_2: Option<Value>,
+}
+
+impl Case {
+    pub fn params(&self) -> Params {
+        self.params.iter().collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Cases {
+    One(Case),
+    Many(Vec<Case>),
+}
+
+/// An example input, and the cases to run it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The file name of the input, without the extension.
+    pub name: String,
+    pub input: String,
+    pub cases: Vec<Case>,
+}
+
+/// Load all of the given day's examples, in order of file name.
+pub fn load(day: u32) -> Result<Vec<Example>, Error> {
+    let dir = examples_dir(day);
+    let entries = std::fs::read_dir(&dir)
+        .map_err(|error| Error::new(format!("could not read {}: {error}", dir.display())))?;
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|error| Error::new(format!("could not read {}: {error}", dir.display())))?
+            .path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(|error| Error::new(format!("could not read {}: {error}", path.display())))
+    };
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let input = read(&path)?;
+            let sidecar = path.with_extension("json");
+            let cases = match serde_json::from_str(&read(&sidecar)?) {
+                Ok(Cases::One(case)) => vec![case],
+                Ok(Cases::Many(cases)) => cases,
+                Err(error) => {
+                    return Err(Error::new(format!(
+                        "invalid answers in {}: {error}",
+                        sidecar.display()
+                    )))
+                }
+            };
+            Ok(Example { name, input, cases })
+        })
+        .collect()
+}
+
+/// Run one case of an example against the given day's solution, returning a description of each
+/// part which did not give the expected answer.
+pub fn run(day: u32, example: &Example, case: &Case) -> Vec<String> {
+    let params = case.params();
+    let label = if params.is_empty() {
+        format!("day{day}/{}", example.name)
+    } else {
+        format!("day{day}/{} ({params})", example.name)
+    };
+    if let Err(error) = days::check_params(&[day], &params) {
+        return vec![format!("{label}: {error}")];
+    }
+    let mut solution = match days::solution(day, &params) {
+        Some(Ok(solution)) => solution,
+        Some(Err(error)) => return vec![format!("{label}: {error}")],
+        None => return vec![format!("{label}: there is no solution for day {day}")],
+    };
+    let mut failures = vec![];
+    for (part, expected) in [(1, &case.part_1), (2, &case.part_2)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let answer = match part {
+            1 => solution.part_1(&example.input),
+            _ => solution.part_2(&example.input),
+        };
+        let expected = expected.to_string();
+        match answer {
+            Ok(answer) if answer == expected => {}
+            Ok(answer) => failures.push(format!(
+                "{label} part {part}: expected {expected}, got {answer}"
+            )),
+            Err(error) => failures.push(format!("{label} part {part}: {error}")),
+        }
+    }
+    failures
+}
+
+/// Run every case of every example for the given day, panicking with a summary of every part
+/// which did not give the expected answer.
+pub fn check(day: u32) {
+    let examples = load(day).unwrap_or_else(|error| panic!("{error}"));
+    assert!(!examples.is_empty(), "day {day} has no examples");
+    let failures: Vec<String> = examples
+        .iter()
+        .flat_map(|example| {
+            example
+                .cases
+                .iter()
+                .flat_map(move |case| run(day, example, case))
+        })
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} example(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cases, Value};
+
+    #[test]
+    fn sidecar() {
+        let Ok(Cases::One(case)) = serde_json::from_str(r#"{"part_1": 16, "part_2": "abc"}"#)
+        else {
+            panic!("expected a single case");
+        };
+        assert_eq!(case.part_1.unwrap().to_string(), "16");
+        assert_eq!(case.part_2, Some(Value::String("abc".into())));
+
+        let Ok(Cases::Many(cases)) =
+            serde_json::from_str(r#"[{"params": {"steps": 6}, "part_2": 16}, {"part_1": 1}]"#)
+        else {
+            panic!("expected a list of cases");
+        };
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].params().to_string(), "steps=6");
+        assert_eq!(cases[1].part_2, None);
+
+        assert!(serde_json::from_str::<Cases>(r#"{"part1": 16}"#).is_err());
+    }
+}
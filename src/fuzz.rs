@@ -0,0 +1,36 @@
+//! Differential testing against random puzzle inputs.
+//!
+//! Days which have a generator for valid random inputs (a `random_input` function) can check
+//! their solutions against a slow but obviously correct reference on many small inputs, which
+//! catches edge cases that neither the examples nor a single real input exercise.
+
+use std::{fmt::Debug, panic::AssertUnwindSafe};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::Error;
+
+/// Run `solution` and `reference` on `cases` random inputs from `generate`, panicking with the
+/// first input they disagree on (or either panics on). The inputs are the same on every run.
+pub fn check<T: PartialEq + Debug>(
+    cases: usize,
+    mut generate: impl FnMut(&mut StdRng) -> String,
+    reference: impl Fn(&str) -> T,
+    solution: impl Fn(&str) -> T,
+) {
+    let mut rng = StdRng::seed_from_u64(2023);
+    for case in 0..cases {
+        let input = generate(&mut rng);
+        let run = |name: &str, f: &dyn Fn(&str) -> T| {
+            std::panic::catch_unwind(AssertUnwindSafe(|| f(&input))).unwrap_or_else(|payload| {
+                panic!(
+                    "case {case}: {}, on input:\n{input}",
+                    Error::from_panic(name, &*payload)
+                )
+            })
+        };
+        let expected = run("the reference", &reference);
+        let actual = run("the solution", &solution);
+        assert_eq!(actual, expected, "case {case}, on input:\n{input}");
+    }
+}
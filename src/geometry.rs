@@ -0,0 +1,292 @@
+use derive_more::{Add, AddAssign, From, Into, Mul, Neg, Sub, SubAssign};
+
+/// One of the four orthogonal directions on a grid, in clockwise order.
+///
+/// North is towards row 0, and West is towards column 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, enum_map::Enum)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const fn opposite(self) -> Self {
+        use Direction::*;
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+
+    /// Turn a quarter turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        use Direction::*;
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    /// Turn a quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        use Direction::*;
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    /// The `(y, x)` offset of one step in this direction.
+    pub const fn offset(self) -> Point2<isize> {
+        match self {
+            Direction::North => Point2 { y: -1, x: 0 },
+            Direction::East => Point2 { y: 0, x: 1 },
+            Direction::South => Point2 { y: 1, x: 0 },
+            Direction::West => Point2 { y: 0, x: -1 },
+        }
+    }
+}
+
+/// One of the eight orthogonal or diagonal directions on a grid, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, enum_map::Enum)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    const fn from_index(idx: usize) -> Self {
+        Self::ALL[idx % 8]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Self::from_index(self as usize + 4)
+    }
+
+    /// Turn an eighth turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    /// Turn an eighth turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 7)
+    }
+
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The `(y, x)` offset of one step in this direction.
+    pub const fn offset(self) -> Point2<isize> {
+        let (y, x) = match self {
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Point2 { y, x }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+impl From<Direction> for Point2<isize> {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl From<Direction8> for Point2<isize> {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+/// A 2D point (or offset) in `(y, x)` order, matching `(row, col)` grid indexing.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    From,
+    Into,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    Neg,
+)]
+pub struct Point2<T> {
+    pub y: T,
+    pub x: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(y: T, x: T) -> Self {
+        Self { y, x }
+    }
+}
+
+impl Point2<isize> {
+    /// Step `n` times in the given direction.
+    pub fn step(self, direction: impl Into<Point2<isize>>, n: isize) -> Self {
+        self + direction.into() * n
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.y.abs_diff(other.y) + self.x.abs_diff(other.x)
+    }
+}
+
+impl Point2<usize> {
+    /// Step once in the given direction, returning `None` if the result would
+    /// not be within `0..height` and `0..width`.
+    pub fn step_within(
+        self,
+        direction: impl Into<Point2<isize>>,
+        (height, width): (usize, usize),
+    ) -> Option<Self> {
+        let offset = direction.into();
+        let y = self.y.checked_add_signed(offset.y)?;
+        let x = self.x.checked_add_signed(offset.x)?;
+        (y < height && x < width).then_some(Point2 { y, x })
+    }
+
+    /// Step `n` times in the given direction, returning `None` if the result would
+    /// not be within `0..height` and `0..width`.
+    pub fn step_n_within(
+        self,
+        direction: impl Into<Point2<isize>>,
+        n: usize,
+        bounds: (usize, usize),
+    ) -> Option<Self> {
+        let offset = direction.into();
+        let n = isize::try_from(n).ok()?;
+        let offset = Point2 {
+            y: offset.y.checked_mul(n)?,
+            x: offset.x.checked_mul(n)?,
+        };
+        self.step_within(offset, bounds)
+    }
+
+    /// The orthogonally adjacent points within `0..height` and `0..width`,
+    /// with the direction to each.
+    pub fn neighbors4_within(
+        self,
+        bounds: (usize, usize),
+    ) -> impl Iterator<Item = (Direction, Self)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step_within(direction, bounds)?)))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.y.abs_diff(other.y) + self.x.abs_diff(other.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Point2};
+
+    #[test]
+    fn turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+    }
+
+    #[test]
+    fn stepping() {
+        let bounds = (3, 4);
+        let origin = Point2::<usize>::new(0, 0);
+        assert_eq!(origin.step_within(Direction::North, bounds), None);
+        assert_eq!(
+            origin.step_within(Direction8::SouthEast, bounds),
+            Some(Point2::new(1, 1))
+        );
+        assert_eq!(
+            origin.step_n_within(Direction::East, 3, bounds),
+            Some(Point2::new(0, 3))
+        );
+        assert_eq!(origin.step_n_within(Direction::East, 4, bounds), None);
+        assert_eq!(
+            origin
+                .neighbors4_within(bounds)
+                .map(|(direction, _)| direction)
+                .collect::<Vec<_>>(),
+            vec![Direction::East, Direction::South]
+        );
+        assert_eq!(
+            Point2::<isize>::new(0, 0).step(Direction::West, 5),
+            Point2::new(0, -5)
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, Direction8, Point2};
+
+/// A cell type which corresponds to a single byte in the AoC grid text format.
+pub trait GridCell: Sized {
+    fn from_byte(byte: u8) -> Option<Self>;
+    fn to_byte(&self) -> u8;
+}
+
+impl GridCell for u8 {
+    #[inline]
+    fn from_byte(byte: u8) -> Option<Self> {
+        Some(byte)
+    }
+    #[inline]
+    fn to_byte(&self) -> u8 {
+        *self
+    }
+}
+
+impl GridCell for char {
+    #[inline]
+    fn from_byte(byte: u8) -> Option<Self> {
+        byte.is_ascii().then_some(byte as char)
+    }
+    #[inline]
+    fn to_byte(&self) -> u8 {
+        u8::try_from(*self).expect("grid cell should be ASCII")
+    }
+}
+
+/// A rectangular grid of cells, stored in row-major order and indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let start = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - start;
+            assert_eq!(
+                *width.get_or_insert(row_width),
+                row_width,
+                "grid rows should all be the same length"
+            );
+            height += 1;
+        }
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    /// Parse the given (trimmed) lines into a grid, calling the given function on each byte.
+    pub fn from_lines_with(lines: &[&str], mut f: impl FnMut(u8) -> T) -> Self {
+        let rows: Vec<Vec<T>> = lines
+            .iter()
+            .map(|line| line.bytes().map(&mut f).collect())
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    /// Parse the given (trimmed) lines into a grid, calling the given function on each byte.
+    /// If any call to the function fails, the whole parsing fails.
+    pub fn try_from_lines_with<E>(
+        lines: &[&str],
+        mut f: impl FnMut(u8) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        let rows: Vec<Vec<T>> = lines
+            .iter()
+            .map(|line| line.bytes().map(&mut f).collect())
+            .collect::<Result<_, E>>()?;
+        Ok(Self::from_rows(rows))
+    }
+
+    /// Parse AoC input format into a grid, calling the given function on each byte
+    /// of each indvidual input line (trimmed).
+    pub fn parse_with(input: &str, f: impl FnMut(u8) -> T) -> Self {
+        Self::from_lines_with(&crate::lines(input), f)
+    }
+
+    /// Parse AoC input format into a grid, calling the given function on each byte
+    /// of each indvidual input line (trimmed). If any call to the function fails, the
+    /// whole parsing fails.
+    pub fn try_parse_with<E>(input: &str, f: impl FnMut(u8) -> Result<T, E>) -> Result<Self, E> {
+        Self::try_from_lines_with(&crate::lines(input), f)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `(height, width)`.
+    #[inline]
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    #[inline]
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    #[inline]
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    #[inline]
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..][..self.width]
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..][..self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(
+        &self,
+        col: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone + '_ {
+        assert!(col < self.width, "column index out of bounds");
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    /// Returns mutable references to each cell in the given column, from top to bottom.
+    pub fn column_mut(&mut self, col: usize) -> Vec<&mut T> {
+        assert!(col < self.width, "column index out of bounds");
+        self.cells
+            .iter_mut()
+            .skip(col)
+            .step_by(self.width)
+            .collect()
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone + '_,
+    > + ExactSizeIterator
+           + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// All positions in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells in the grid with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    /// All cells in the grid with their positions, in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        self.positions().zip(&mut self.cells)
+    }
+
+    /// Returns the first position (in row-major order) of a cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the first position (in row-major order) of a cell matching the predicate.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(&mut pred)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    /// The in-bounds orthogonally adjacent positions, clockwise from north.
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction))
+    }
+
+    /// The in-bounds orthogonally and diagonally adjacent positions, clockwise from north.
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction))
+    }
+
+    /// Offset a position by `(drow, dcol)` (or one step in a [`Direction`](crate::Direction)),
+    /// returning `None` if the result is out of bounds.
+    #[inline]
+    pub fn offset(
+        &self,
+        position: (usize, usize),
+        offset: impl Into<Point2<isize>>,
+    ) -> Option<(usize, usize)> {
+        Point2::from(position)
+            .step_within(offset, self.size())
+            .map(Into::into)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns the grid reflected across its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Returns the grid rotated a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flat_map(Iterator::rev).cloned().collect(),
+        }
+    }
+
+    /// Returns the grid rotated a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().rev().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T: GridCell> Grid<T> {
+    /// Parse AoC input format into a grid of cells.
+    ///
+    /// Panics if a byte does not correspond to a cell.
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |byte| {
+            T::from_byte(byte).unwrap_or_else(|| panic!("invalid grid cell {:?}", byte as char))
+        })
+    }
+
+    /// Parse the given (trimmed) lines into a grid of cells.
+    ///
+    /// Panics if a byte does not correspond to a cell.
+    pub fn from_lines(lines: &[&str]) -> Self {
+        Self::from_lines_with(lines, |byte| {
+            T::from_byte(byte).unwrap_or_else(|| panic!("invalid grid cell {:?}", byte as char))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).expect("grid index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position).expect("grid index out of bounds")
+    }
+}
+
+impl<T: GridCell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx != 0 {
+                writeln!(f)?;
+            }
+            let row: Vec<u8> = row.iter().map(GridCell::to_byte).collect();
+            f.write_str(&String::from_utf8_lossy(&row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "ab.\n.S#\n";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::<u8>::parse(INPUT);
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[(1, 2)], b'#');
+        assert_eq!(grid.find(&b'S'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "ab.\n.S#");
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::<u8>::parse(INPUT);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = Grid::<u8>::parse(INPUT);
+        assert_eq!(grid.transpose().to_string(), "a.\nbS\n.#");
+        assert_eq!(grid.rotate_clockwise().to_string(), ".a\nSb\n#.");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), ".#\nbS\na.");
+        assert_eq!(
+            grid.column(1).copied().collect::<Vec<_>>(),
+            vec![b'b', b'S']
+        );
+    }
+}
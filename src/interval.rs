@@ -0,0 +1,529 @@
+use std::ops::{Add, Range, Sub};
+
+use itertools::Itertools;
+
+use crate::Error;
+
+/// A set of values, stored as sorted, disjoint, non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Normalize the given ranges: sort them, and merge overlapping or adjacent ranges.
+    fn from_unsorted(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_unstable_by_key(|range| range.start);
+        let mut normalized: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match normalized.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => normalized.push(range),
+            }
+        }
+        Self { ranges: normalized }
+    }
+
+    /// The ranges of values in the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    /// Add every value in `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&Self::from_iter([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_unsorted(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever range ends first cannot overlap anything else in the other set.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip the ranges of `other` entirely before this range.
+            while other.ranges.get(j).is_some_and(|b| b.end <= start) {
+                j += 1;
+            }
+            let mut k = j;
+            while let Some(b) = other.ranges.get(k).filter(|b| b.start < range.end) {
+                if start < b.start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T
+    where
+        T: Default + Add<Output = T> + Sub<Output = T>,
+    {
+        self.ranges
+            .iter()
+            .fold(T::default(), |acc, range| acc + (range.end - range.start))
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+/// A piece of a [`RangeMap`], which shifts every value in `range` by `offset`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Piece {
+    range: Range<u64>,
+    /// An `i128`, since the shift between two `u64`s (such as the net shift of a composed map)
+    /// need not fit in an `i64`.
+    offset: i128,
+}
+
+/// A function from `u64` to `u64` which is piecewise affine with slope one: it shifts each of
+/// a set of disjoint ranges by a constant offset, and maps every other value to itself.
+///
+/// The domain is `0..u64::MAX`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeMap {
+    /// Sorted, disjoint, with non-zero offsets, and with no adjacent pieces with the same offset.
+    pieces: Vec<Piece>,
+}
+
+impl RangeMap {
+    /// The identity map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make the map send `src` to the range of the same length starting at `dst_start`.
+    ///
+    /// Fails if `src` overlaps a range that was already inserted, or if the destination range
+    /// does not fit in a `u64`.
+    pub fn insert(&mut self, src: Range<u64>, dst_start: u64) -> Result<(), Error> {
+        let offset = i128::from(dst_start) - i128::from(src.start);
+        if src.is_empty() {
+            return Ok(());
+        }
+        if dst_start.checked_add(src.end - src.start).is_none() {
+            return Err(Error::new(format!(
+                "range {src:?} shifted to {dst_start} does not fit in a u64"
+            )));
+        }
+        let idx = self
+            .pieces
+            .partition_point(|piece| piece.range.end <= src.start);
+        if self
+            .pieces
+            .get(idx)
+            .is_some_and(|piece| piece.range.start < src.end)
+        {
+            return Err(Error::new(format!(
+                "range {src:?} overlaps {:?}",
+                self.pieces[idx].range
+            )));
+        }
+        if offset != 0 {
+            self.pieces.insert(idx, Piece { range: src, offset });
+            self.normalize();
+        }
+        Ok(())
+    }
+
+    /// Merge adjacent pieces with the same offset, and remove pieces with no offset.
+    fn normalize(&mut self) {
+        let mut pieces: Vec<Piece> = Vec::with_capacity(self.pieces.len());
+        for piece in self.pieces.drain(..) {
+            if piece.offset == 0 || piece.range.is_empty() {
+                continue;
+            }
+            match pieces.last_mut() {
+                Some(last)
+                    if last.range.end == piece.range.start && last.offset == piece.offset =>
+                {
+                    last.range.end = piece.range.end;
+                }
+                _ => pieces.push(piece),
+            }
+        }
+        self.pieces = pieces;
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let idx = self
+            .pieces
+            .partition_point(|piece| piece.range.end <= value);
+        match self.pieces.get(idx) {
+            Some(piece) if piece.range.contains(&value) => shift(value, piece.offset),
+            _ => value,
+        }
+    }
+
+    /// Split `range` into the parts which this map shifts by a single offset, in order, with
+    /// those offsets.
+    fn segments(&self, range: Range<u64>) -> impl Iterator<Item = (Range<u64>, i128)> + '_ {
+        let idx = self
+            .pieces
+            .partition_point(|piece| piece.range.end <= range.start);
+        let mut start = range.start;
+        let mut pieces = self.pieces[idx..].iter().peekable();
+        std::iter::from_fn(move || {
+            if start >= range.end {
+                return None;
+            }
+            let segment = match pieces.next_if(|piece| piece.range.start <= start) {
+                Some(piece) => (start..piece.range.end.min(range.end), piece.offset),
+                None => {
+                    let end = pieces
+                        .peek()
+                        .map_or(range.end, |piece| piece.range.start.min(range.end));
+                    (start..end, 0)
+                }
+            };
+            start = segment.0.end;
+            Some(segment)
+        })
+    }
+
+    /// The image of `set` under this map.
+    pub fn apply(&self, set: &RangeSet<u64>) -> RangeSet<u64> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.segments(range.clone()))
+            .map(|(range, offset)| shift(range.start, offset)..shift(range.end, offset))
+            .collect()
+    }
+
+    /// The map which applies this map, and then `then`.
+    pub fn then(&self, then: &RangeMap) -> RangeMap {
+        let mut pieces = vec![];
+        for (range, offset) in self.segments(0..u64::MAX) {
+            let image = shift(range.start, offset)..shift(range.end, offset);
+            for (then_range, then_offset) in then.segments(image) {
+                pieces.push(Piece {
+                    range: shift(then_range.start, -offset)..shift(then_range.end, -offset),
+                    offset: offset + then_offset,
+                });
+            }
+        }
+        let mut map = RangeMap { pieces };
+        map.normalize();
+        map
+    }
+
+    /// The map which applies each of `maps` in order.
+    pub fn compose<'a>(maps: impl IntoIterator<Item = &'a RangeMap>) -> RangeMap {
+        maps.into_iter()
+            .fold(RangeMap::new(), |composed, map| composed.then(map))
+    }
+
+    /// The values at which the offset this map applies changes, in order.
+    pub fn breakpoints(&self) -> Vec<u64> {
+        let mut breakpoints: Vec<u64> = self
+            .pieces
+            .iter()
+            .flat_map(|piece| [piece.range.start, piece.range.end])
+            .collect();
+        breakpoints.dedup();
+        breakpoints
+    }
+
+    /// The values which this map sends into `set`.
+    pub fn preimage(&self, set: &RangeSet<u64>) -> RangeSet<u64> {
+        self.segments(0..u64::MAX)
+            .flat_map(|(range, offset)| {
+                let image = shift(range.start, offset)..shift(range.end, offset);
+                set.intersection(&RangeSet::from_iter([image]))
+                    .ranges
+                    .into_iter()
+                    .map(move |range| shift(range.start, -offset)..shift(range.end, -offset))
+            })
+            .collect()
+    }
+
+    /// The inverse of this map.
+    ///
+    /// Fails if this map is not a bijection, i.e. if some ranges are sent onto the same values,
+    /// leaving other values unreached.
+    pub fn inverse(&self) -> Result<RangeMap, Error> {
+        let mut pieces: Vec<Piece> = self
+            .segments(0..u64::MAX)
+            .map(|(range, offset)| Piece {
+                range: shift(range.start, offset)..shift(range.end, offset),
+                offset: -offset,
+            })
+            .collect();
+        pieces.sort_unstable_by_key(|piece| piece.range.start);
+        let mut expected_start = 0;
+        for piece in &pieces {
+            if piece.range.start != expected_start {
+                return Err(Error::new(format!(
+                    "the map is not a bijection: {} is reached {}",
+                    expected_start.min(piece.range.start),
+                    if piece.range.start < expected_start {
+                        "more than once"
+                    } else {
+                        "from no value"
+                    },
+                )));
+            }
+            expected_start = piece.range.end;
+        }
+        let mut map = RangeMap { pieces };
+        map.normalize();
+        Ok(map)
+    }
+
+    /// Precompute the minimum of the image of every segment of this map, to find the minimum
+    /// image of any range in logarithmic time.
+    pub fn min_image_query(&self) -> MinImageQuery {
+        let segments: Vec<(Range<u64>, i128)> = self.segments(0..u64::MAX).collect();
+        // Each segment is shifted by a single offset, so its minimum image is its start's.
+        let mut table = vec![segments
+            .iter()
+            .map(|(range, offset)| shift(range.start, *offset))
+            .collect_vec()];
+        let mut width = 1;
+        while width * 2 <= segments.len() {
+            let previous = table.last().unwrap();
+            let level = (0..=segments.len() - width * 2)
+                .map(|i| previous[i].min(previous[i + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+        MinImageQuery { segments, table }
+    }
+}
+
+/// The minimum image under a [`RangeMap`] of any range, in logarithmic time.
+///
+/// Created by [`RangeMap::min_image_query`].
+#[derive(Debug, Clone)]
+pub struct MinImageQuery {
+    /// Every segment of the map's domain, with the offset the map shifts it by.
+    segments: Vec<(Range<u64>, i128)>,
+    /// A sparse table: `table[k][i]` is the minimum image of segments `i..i + 2^k`.
+    table: Vec<Vec<u64>>,
+}
+
+impl MinImageQuery {
+    /// The smallest value the map sends any value in `range` to, or `None` if `range` is empty.
+    pub fn min(&self, range: Range<u64>) -> Option<u64> {
+        if range.is_empty() {
+            return None;
+        }
+        let segment_of = |value: u64| {
+            self.segments
+                .partition_point(|(segment, _)| segment.end <= value)
+        };
+        let first = segment_of(range.start);
+        let last = segment_of(range.end - 1);
+        // The first segment may only be partly covered, but every other segment is covered
+        // from its start.
+        let partial = shift(range.start, self.segments[first].1);
+        if first == last {
+            return Some(partial);
+        }
+        let (start, end) = (first + 1, last + 1);
+        let level = (end - start).ilog2() as usize;
+        let width = 1 << level;
+        let whole = self.table[level][start].min(self.table[level][end - width]);
+        Some(partial.min(whole))
+    }
+}
+
+fn shift(value: u64, offset: i128) -> u64 {
+    u64::try_from(i128::from(value) + offset).expect("shifted value should fit in a u64")
+}
+
+#[cfg(test)]
+// The expected ranges are written as arrays, even when there is only one range.
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_set() {
+        let a = RangeSet::from_iter([5..10, 0..3, 2..4, 10..12, 20..20]);
+        assert_eq!(a.ranges(), [0..4, 5..12]);
+        let b = RangeSet::from_iter([3..6, 8..9, 11..30]);
+        assert_eq!(a.union(&b).ranges(), [0..30]);
+        assert_eq!(a.intersection(&b).ranges(), [3..4, 5..6, 8..9, 11..12]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 6..8, 9..11]);
+        assert_eq!(b.difference(&a).ranges(), [4..5, 12..30]);
+        assert_eq!(a.len(), 11);
+        assert!(a.contains(0) && a.contains(11) && !a.contains(4) && !a.contains(12));
+        let mut c = a.clone();
+        c.insert(4..5);
+        assert_eq!(c.ranges(), [0..12]);
+    }
+
+    #[test]
+    fn range_map() {
+        // The seed-to-soil map from the day 5 example.
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50).unwrap();
+        map.insert(50..98, 52).unwrap();
+        assert!(map.insert(90..110, 0).is_err());
+        assert_eq!(
+            [0, 49, 50, 97, 98, 99, 100].map(|x| map.get(x)),
+            [0, 49, 52, 99, 50, 51, 100]
+        );
+
+        let seeds = RangeSet::from_iter([79..93, 55..68]);
+        assert_eq!(map.apply(&seeds).ranges(), [57..70, 81..95]);
+
+        let mut other = RangeMap::new();
+        other.insert(0..60, 1000).unwrap();
+        let composed = map.then(&other);
+        for x in 0..200 {
+            assert_eq!(composed.get(x), other.get(map.get(x)), "{x}");
+        }
+        assert_eq!(composed.apply(&seeds), other.apply(&map.apply(&seeds)),);
+    }
+
+    #[test]
+    fn range_map_matches_pointwise() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(5);
+        let random_map = |rng: &mut StdRng| {
+            let mut map = RangeMap::new();
+            for _ in 0..5 {
+                let start = rng.gen_range(0..100);
+                let _ = map.insert(start..start + rng.gen_range(0..30), rng.gen_range(0..100));
+            }
+            map
+        };
+        for _ in 0..100 {
+            let first = random_map(&mut rng);
+            let second = random_map(&mut rng);
+            let composed = first.then(&second);
+            let set: RangeSet<u64> = (0..3)
+                .map(|_| {
+                    let start = rng.gen_range(0..150);
+                    start..start + rng.gen_range(0..30)
+                })
+                .collect();
+            let pointwise: RangeSet<u64> = set
+                .ranges()
+                .iter()
+                .flat_map(|range| range.clone())
+                .map(|x| second.get(first.get(x)))
+                .map(|x| x..x + 1)
+                .collect();
+            assert_eq!(second.apply(&first.apply(&set)), pointwise);
+            assert_eq!(composed.apply(&set), pointwise);
+        }
+    }
+
+    #[test]
+    fn range_map_queries() {
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50).unwrap();
+        map.insert(50..98, 52).unwrap();
+        assert_eq!(map.breakpoints(), [50, 98, 100]);
+
+        let inverse = map.inverse().unwrap();
+        assert_eq!(map.then(&inverse), RangeMap::new());
+        assert_eq!(inverse.get(52), 50);
+        let mut not_bijection = RangeMap::new();
+        not_bijection.insert(0..10, 20).unwrap();
+        assert!(not_bijection.inverse().is_err());
+        assert_eq!(
+            not_bijection
+                .preimage(&RangeSet::from_iter([15..25]))
+                .ranges(),
+            [0..5, 15..25]
+        );
+
+        let composed = RangeMap::compose([&map, &not_bijection, &inverse]);
+        let query = composed.min_image_query();
+        for start in 0..120 {
+            for end in start..120 {
+                let expected = composed.apply(&RangeSet::from_iter([start..end])).min();
+                assert_eq!(query.min(start..end), expected, "{start}..{end}");
+            }
+        }
+        assert_eq!(query.min(0..u64::MAX), Some(10));
+    }
+
+    #[test]
+    fn large_offsets() {
+        // Each map shifts by less than `i64::MAX`, but together they shift by more.
+        let mut first = RangeMap::new();
+        first.insert(0..10, 3 << 61).unwrap();
+        let mut second = RangeMap::new();
+        second.insert(3 << 61..(3 << 61) + 10, 3 << 62).unwrap();
+        let composed = first.then(&second);
+        assert_eq!(composed.get(5), (3 << 62) + 5);
+        assert_eq!(
+            composed.apply(&RangeSet::from_iter([0..10])).ranges(),
+            [3 << 62..(3 << 62) + 10]
+        );
+        assert_eq!(
+            composed
+                .preimage(&RangeSet::from_iter([3 << 62..(3 << 62) + 1]))
+                .ranges(),
+            [0..1, 3 << 61..(3 << 61) + 1, 3 << 62..(3 << 62) + 1]
+        );
+
+        let mut widest = RangeMap::new();
+        widest.insert(0..1, u64::MAX - 1).unwrap();
+        assert_eq!(widest.get(0), u64::MAX - 1);
+    }
+}
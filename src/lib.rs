@@ -4,11 +4,43 @@ use std::{
     hash::Hash,
     panic::{AssertUnwindSafe, RefUnwindSafe, UnwindSafe},
     str::FromStr,
+    time::{Duration, Instant},
 };
 
 use either::Either;
 pub use itertools::Itertools;
 
+pub mod bench;
+pub mod circuit;
+pub mod cycle;
+pub mod days;
+pub mod diagram;
+mod error;
+pub mod examples;
+#[cfg(test)]
+mod fuzz;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod nonogram;
+mod params;
+pub mod polygon;
+pub mod rational;
+pub mod runner;
+pub mod search;
+mod solution;
+pub use circuit::Circuit;
+pub use diagram::Diagram;
+pub use error::{parse_field, try_parse_lines_located, Error};
+pub use geometry::{Direction, Direction8, Point2};
+pub use grid::{Grid, GridCell};
+pub use interval::{MinImageQuery, RangeMap, RangeSet};
+pub use params::Params;
+pub use polygon::Polygon;
+pub use rational::Rational;
+pub use search::{astar, dijkstra, ShortestPath};
+pub use solution::{SeparateFunctions, Solution, Timings};
+
 pub trait IntoIteratorExt: IntoIterator + Sized {
     #[inline]
     fn collect_hashset(self) -> HashSet<<Self as IntoIterator>::Item>
@@ -292,6 +324,7 @@ pub fn common_item3<'a, T: PartialOrd>(a: &'a [T], b: &[T], c: &[T]) -> Option<&
 pub struct SingleFunction<F, A> {
     #[allow(clippy::type_complexity)]
     inner: Option<Either<F, std::thread::Result<(A, Option<A>)>>>,
+    elapsed: Option<Duration>,
 }
 
 impl<F, A, T> SingleFunction<F, A>
@@ -302,55 +335,155 @@ where
     pub fn new(f: F) -> Self {
         Self {
             inner: Some(Either::Left(f)),
+            elapsed: None,
         }
     }
 
-    pub fn part_1<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a A + 'a {
-        |input| {
-            let output = match self.inner.take().unwrap() {
-                Either::Left(runner) => {
-                    let output = std::panic::catch_unwind(|| runner(input));
-                    output.map(|(a, b)| (a, b.into()))
-                }
-                Either::Right(output) => output,
-            };
-            self.inner = Some(Either::Right(output));
-            match self.inner.as_ref().unwrap().as_ref().right().unwrap() {
-                Ok((p1, _p2)) => p1,
-                Err(_) => panic!("solution function panicked"),
+    /// How long the solution function took, if it has been called.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+
+    fn run(&mut self, input: &str) -> &std::thread::Result<(A, Option<A>)> {
+        let output = match self.inner.take().unwrap() {
+            Either::Left(runner) => {
+                let start = Instant::now();
+                let output = std::panic::catch_unwind(|| runner(input));
+                self.elapsed = Some(start.elapsed());
+                output.map(|(a, b)| (a, b.into()))
             }
+            Either::Right(output) => output,
+        };
+        self.inner = Some(Either::Right(output));
+        self.inner.as_ref().unwrap().as_ref().right().unwrap()
+    }
+
+    pub fn part_1<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a A + 'a {
+        |input| match self.run(input) {
+            Ok((p1, _p2)) => p1,
+            Err(_) => panic!("solution function panicked"),
         }
     }
     pub fn part_2<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a A + 'a {
-        |input| {
-            let output = match self.inner.take().unwrap() {
-                Either::Left(runner) => {
-                    let output = std::panic::catch_unwind(|| runner(input));
-                    output.map(|(a, b)| (a, b.into()))
+        |input| match self.run(input) {
+            Ok((_p1, Some(p2))) => p2,
+            Ok((_p1, None)) => panic!("solution function only gave part 1"),
+            Err(_) => panic!("solution function panicked"),
+        }
+    }
+
+    pub fn try_part_1<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a A, Error> + 'a {
+        |input| match self.run(input) {
+            Ok((p1, _p2)) => Ok(p1),
+            Err(payload) => Err(Error::from_panic("solution function", &**payload)),
+        }
+    }
+    pub fn try_part_2<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a A, Error> + 'a {
+        |input| match self.run(input) {
+            Ok((_p1, Some(p2))) => Ok(p2),
+            Ok((_p1, None)) => Err(Error::new("solution function only gave part 1")),
+            Err(payload) => Err(Error::from_panic("solution function", &**payload)),
+        }
+    }
+}
+
+pub struct TrySingleFunction<F, A> {
+    #[allow(clippy::type_complexity)]
+    inner: Option<Either<F, Result<(A, Option<A>), Error>>>,
+    elapsed: Option<Duration>,
+}
+
+impl<F, A, T> TrySingleFunction<F, A>
+where
+    T: Into<Option<A>>,
+    F: FnOnce(&str) -> Result<(A, T), Error>,
+{
+    pub fn new(f: F) -> Self {
+        Self {
+            inner: Some(Either::Left(f)),
+            elapsed: None,
+        }
+    }
+
+    /// How long the solution function took, if it has been called.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+
+    fn run(&mut self, input: &str) -> Result<&(A, Option<A>), Error> {
+        let output = match self.inner.take().unwrap() {
+            Either::Left(runner) => {
+                let start = Instant::now();
+                let output = std::panic::catch_unwind(AssertUnwindSafe(|| runner(input)));
+                self.elapsed = Some(start.elapsed());
+                match output {
+                    Ok(output) => output
+                        .map(|(a, b)| (a, b.into()))
+                        .map_err(|error| error.locate(input)),
+                    Err(payload) => Err(Error::from_panic("solution function", &*payload)),
                 }
-                Either::Right(output) => output,
-            };
-            self.inner = Some(Either::Right(output));
-            match self.inner.as_ref().unwrap().as_ref().right().unwrap() {
-                Ok((_p1, Some(p2))) => p2,
-                Ok((_p1, None)) => panic!("solution function only gave part 1"),
-                Err(_) => panic!("solution function panicked"),
             }
+            Either::Right(output) => output,
+        };
+        self.inner = Some(Either::Right(output));
+        self.inner
+            .as_ref()
+            .unwrap()
+            .as_ref()
+            .right()
+            .unwrap()
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    pub fn try_part_1<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a A, Error> + 'a {
+        |input| self.run(input).map(|(p1, _p2)| p1)
+    }
+
+    pub fn try_part_2<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a A, Error> + 'a {
+        |input| match self.run(input)? {
+            (_p1, Some(p2)) => Ok(p2),
+            (_p1, None) => Err(Error::new("solution function only gave part 1")),
         }
     }
+
+    /// Like [`TrySingleFunction::try_part_1`], but panics with the error message on failure.
+    pub fn part_1<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a A + 'a {
+        |input| self.try_part_1()(input).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Like [`TrySingleFunction::try_part_2`], but panics with the error message on failure.
+    pub fn part_2<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a A + 'a {
+        |input| self.try_part_2()(input).unwrap_or_else(|error| panic!("{error}"))
+    }
 }
 
 struct MyOnce<F, R> {
     inner: Either<F, std::thread::Result<R>>,
+    elapsed: Option<Duration>,
 }
 
 impl<F, R> MyOnce<F, R> {
     fn new(callable: F) -> Self {
         Self {
             inner: Either::Left(callable),
+            elapsed: None,
         }
     }
 
+    /// How long the call took, if it has been called.
+    fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+
     fn call_once<U>(&mut self, input: U, name: &str) -> &R
     where
         F: FnOnce(U) -> R,
@@ -359,17 +492,27 @@ impl<F, R> MyOnce<F, R> {
             .unwrap_or_else(|_| panic!("{name} panicked"))
     }
 
+    fn call_once_or_error<U>(&mut self, input: U, name: &str) -> Result<&R, Error>
+    where
+        F: FnOnce(U) -> R,
+    {
+        self.try_call_once(input)
+            .map_err(|payload| Error::from_panic(name, &**payload))
+    }
+
     fn try_call_once<U>(&mut self, input: U) -> Result<&R, &Box<dyn Any + Send>>
     where
         F: FnOnce(U) -> R,
     {
         if self.inner.is_left() {
+            let start = Instant::now();
             replace_with::replace_with_or_abort(&mut self.inner, |inner| {
                 let callable = inner.left().unwrap();
                 Either::Right(std::panic::catch_unwind(AssertUnwindSafe(|| {
                     callable(input)
                 })))
-            })
+            });
+            self.elapsed = Some(start.elapsed());
         }
         match &self.inner {
             Either::Right(value) => value.as_ref(),
@@ -399,6 +542,15 @@ where
         }
     }
 
+    /// How long each function took, for the functions that have been called.
+    pub fn timings(&self) -> Timings {
+        Timings {
+            parse: self.parser.elapsed(),
+            part_1: self.part_1.elapsed(),
+            part_2: self.part_2.elapsed(),
+        }
+    }
+
     pub fn part_1<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a R1 + 'a {
         |input| {
             let parsed = self.parser.call_once(input, "parser");
@@ -412,11 +564,145 @@ where
             self.part_2.call_once(parsed, "part 2 solution function")
         }
     }
+
+    pub fn try_part_1<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a R1, Error> + 'a {
+        |input| {
+            let parsed = self.parser.call_once_or_error(input, "parser")?;
+            self.part_1
+                .call_once_or_error(parsed, "part 1 solution function")
+        }
+    }
+
+    pub fn try_part_2<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a R2, Error> + 'a {
+        |input| {
+            let parsed = self.parser.call_once_or_error(input, "parser")?;
+            self.part_2
+                .call_once_or_error(parsed, "part 2 solution function")
+        }
+    }
+}
+
+/// What a [`TryPreParsed`] part function returns: either the answer itself, or a `Result` which
+/// may instead hold an [`Error`].
+pub trait PartOutput {
+    type Answer;
+
+    fn as_result(&self) -> Result<&Self::Answer, &Error>;
+}
+
+impl<T> PartOutput for Result<T, Error> {
+    type Answer = T;
+
+    fn as_result(&self) -> Result<&T, &Error> {
+        self.as_ref()
+    }
+}
+
+macro_rules! infallible_part_output {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl PartOutput for $ty {
+                type Answer = $ty;
+
+                fn as_result(&self) -> Result<&$ty, &Error> {
+                    Ok(self)
+                }
+            }
+        )*
+    };
+}
+
+infallible_part_output!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
+/// Like [`PreParsed`], but the parser may fail with an [`Error`], and so may each solution
+/// function if it returns a `Result` (see [`PartOutput`]).
+///
+/// Only the parser sees the input, so only its errors are given the line and column they came
+/// from; the solution functions' errors are passed on as they are.
+pub struct TryPreParsed<ParseFn, Parsed, Part1Fn, Part2Fn, Part1Out, Part2Out> {
+    parser: MyOnce<ParseFn, Result<Parsed, Error>>,
+    part_1: MyOnce<Part1Fn, Part1Out>,
+    part_2: MyOnce<Part2Fn, Part2Out>,
+}
+
+impl<PF, P, F1, F2, O1, O2> TryPreParsed<PF, P, F1, F2, O1, O2>
+where
+    PF: FnOnce(&str) -> Result<P, Error>,
+    F1: FnOnce(&P) -> O1 + UnwindSafe,
+    F2: FnOnce(&P) -> O2 + UnwindSafe,
+    O1: PartOutput,
+    O2: PartOutput,
+    P: RefUnwindSafe,
+{
+    pub fn new(parser: PF, part_1: F1, part_2: F2) -> Self {
+        Self {
+            parser: MyOnce::new(parser),
+            part_1: MyOnce::new(part_1),
+            part_2: MyOnce::new(part_2),
+        }
+    }
+
+    /// How long each function took, for the functions that have been called.
+    pub fn timings(&self) -> Timings {
+        Timings {
+            parse: self.parser.elapsed(),
+            part_1: self.part_1.elapsed(),
+            part_2: self.part_2.elapsed(),
+        }
+    }
+
+    fn parse<'a>(
+        parser: &'a mut MyOnce<PF, Result<P, Error>>,
+        input: &str,
+    ) -> Result<&'a P, Error> {
+        parser
+            .call_once_or_error(input, "parser")?
+            .as_ref()
+            .map_err(|error| error.clone().locate(input).context("parser failed"))
+    }
+
+    pub fn try_part_1<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a O1::Answer, Error> + 'a {
+        |input| {
+            let parsed = Self::parse(&mut self.parser, input)?;
+            self.part_1
+                .call_once_or_error(parsed, "part 1 solution function")?
+                .as_result()
+                .map_err(Clone::clone)
+        }
+    }
+
+    pub fn try_part_2<'a>(
+        &'a mut self,
+    ) -> impl for<'b> FnOnce(&'b str) -> Result<&'a O2::Answer, Error> + 'a {
+        |input| {
+            let parsed = Self::parse(&mut self.parser, input)?;
+            self.part_2
+                .call_once_or_error(parsed, "part 2 solution function")?
+                .as_result()
+                .map_err(Clone::clone)
+        }
+    }
+
+    /// Like [`TryPreParsed::try_part_1`], but panics with the error message on failure.
+    pub fn part_1<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a O1::Answer + 'a {
+        |input| self.try_part_1()(input).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Like [`TryPreParsed::try_part_2`], but panics with the error message on failure.
+    pub fn part_2<'a>(&'a mut self) -> impl for<'b> FnOnce(&'b str) -> &'a O2::Answer + 'a {
+        |input| self.try_part_2()(input).unwrap_or_else(|error| panic!("{error}"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{groups, lines};
+    use crate::{groups, lines, Error, TryPreParsed, TrySingleFunction};
 
     #[test]
     fn test_list() {
@@ -433,4 +719,56 @@ mod tests {
             vec![vec!["a", "b", "c"], vec!["d", "e"]],
         );
     }
+
+    #[test]
+    fn test_try_single_function() {
+        let mut both = TrySingleFunction::new(|input: &str| {
+            let value: u32 = input
+                .parse()
+                .map_err(|_| Error::new("bad").at(input, input))?;
+            Ok((value, None))
+        });
+        assert_eq!(both.try_part_1()("3"), Ok(&3));
+        assert_eq!(
+            both.try_part_2()("3").unwrap_err().to_string(),
+            "solution function only gave part 1"
+        );
+
+        let mut both = TrySingleFunction::new(|input: &str| {
+            assert!(!input.is_empty(), "empty input");
+            Ok((1, 2))
+        });
+        assert_eq!(
+            both.try_part_1()("").unwrap_err().to_string(),
+            "solution function panicked: empty input"
+        );
+    }
+
+    #[test]
+    fn test_try_pre_parsed() {
+        let parse = |input: &str| -> Result<u32, Error> {
+            input
+                .parse()
+                .map_err(|_| Error::new("bad").at(input, input))
+        };
+        let part_1 = |value: &u32| value * 2;
+        let part_2 = |value: &u32| match value {
+            0 => Err(Error::new("zero")),
+            _ => Ok(value * 3),
+        };
+
+        let mut both = TryPreParsed::new(parse, part_1, part_2);
+        assert_eq!(both.try_part_1()("3"), Ok(&6));
+        assert_eq!(both.try_part_2()("3"), Ok(&9));
+
+        let mut both = TryPreParsed::new(parse, part_1, part_2);
+        assert_eq!(both.try_part_1()("0"), Ok(&0));
+        assert_eq!(both.try_part_2()("0").unwrap_err().to_string(), "zero");
+
+        let mut both = TryPreParsed::new(parse, part_1, part_2);
+        assert_eq!(
+            both.try_part_1()("x").unwrap_err().to_string(),
+            "line 1, column 1: parser failed: bad"
+        );
+    }
 }
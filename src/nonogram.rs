@@ -0,0 +1,355 @@
+use std::{
+    fmt::Debug,
+    ops::{AddAssign, SubAssign},
+};
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+
+use crate::Grid;
+
+/// A number of arrangements. Use [`BigUint`] when the count may not fit in a machine integer.
+pub trait Count:
+    Clone + Ord + Debug + From<u8> + for<'a> AddAssign<&'a Self> + for<'a> SubAssign<&'a Self>
+{
+    /// A uniformly random count less than this one, which must not be zero.
+    fn random_below(&self, rng: &mut impl Rng) -> Self;
+}
+
+macro_rules! impl_count {
+    ($($ty:ty),*) => {
+        $(impl Count for $ty {
+            fn random_below(&self, rng: &mut impl Rng) -> Self {
+                rng.gen_range(0..*self)
+            }
+        })*
+    };
+}
+
+impl_count!(u32, u64, u128, usize);
+
+impl Count for BigUint {
+    fn random_below(&self, rng: &mut impl Rng) -> Self {
+        rng.gen_biguint_below(self)
+    }
+}
+
+/// Solves one line of a nonogram: finds the ways to place runs of filled cells with the given
+/// lengths (`clues`), in order and separated by at least one empty cell, consistent with the
+/// cells which are already known to be filled (`Some(true)`) or empty (`Some(false)`).
+#[derive(Debug, Clone)]
+pub struct LineSolver<C = usize> {
+    cells: Vec<Option<bool>>,
+    clues: Vec<usize>,
+    /// `fillable[i]` is how many cells starting at cell `i` could be filled.
+    fillable: Vec<usize>,
+    /// `ways[i][j]` is the number of ways to place `clues[j..]` in `cells[i..]`.
+    ways: Vec<Vec<C>>,
+}
+
+/// A step from placing the runs from `clue` onwards at `cell` onwards, to placing the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// Leave the cell empty.
+    Empty,
+    /// Place the run for `clue` starting at the cell, followed by an empty cell if there is one.
+    Run,
+}
+
+impl<C: Count> LineSolver<C> {
+    pub fn new(cells: &[Option<bool>], clues: &[usize]) -> Self {
+        let (n, m) = (cells.len(), clues.len());
+        let mut fillable = vec![0; n + 1];
+        for i in (0..n).rev() {
+            if cells[i] != Some(false) {
+                fillable[i] = fillable[i + 1] + 1;
+            }
+        }
+        let mut solver = Self {
+            cells: cells.to_vec(),
+            clues: clues.to_vec(),
+            fillable,
+            ways: vec![vec![C::from(0); m + 1]; n + 1],
+        };
+        solver.ways[n][m] = C::from(1);
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                let mut ways = C::from(0);
+                for step in [Step::Empty, Step::Run] {
+                    if let Some((next_i, next_j)) = solver.step(i, j, step) {
+                        ways += &solver.ways[next_i][next_j];
+                    }
+                }
+                solver.ways[i][j] = ways;
+            }
+        }
+        solver
+    }
+
+    /// Where taking `step` from cell `i` and clue `j` leads, if it is consistent with the known
+    /// cells.
+    fn step(&self, i: usize, j: usize, step: Step) -> Option<(usize, usize)> {
+        let n = self.cells.len();
+        match step {
+            Step::Empty => (i < n && self.cells[i] != Some(true)).then_some((i + 1, j)),
+            Step::Run => {
+                let len = *self.clues.get(j)?;
+                let end = i + len;
+                let fits = i < n
+                    && self.fillable[i] >= len
+                    && (end == n || (end < n && self.cells[end] != Some(true)));
+                fits.then_some(((end + 1).min(n), j + 1))
+            }
+        }
+    }
+
+    /// The steps from cell `i` and clue `j` which lead to at least one arrangement.
+    fn live_steps(&self, i: usize, j: usize) -> impl Iterator<Item = (Step, usize, usize)> + '_ {
+        let zero = C::from(0);
+        [Step::Empty, Step::Run]
+            .into_iter()
+            .filter_map(move |step| {
+                let (next_i, next_j) = self.step(i, j, step)?;
+                (self.ways[next_i][next_j] != zero).then_some((step, next_i, next_j))
+            })
+    }
+
+    /// Mark the cells filled by `step` from cell `i` and clue `j`.
+    fn fill(&self, line: &mut [bool], i: usize, j: usize, step: Step) {
+        if step == Step::Run {
+            line[i..i + self.clues[j]].fill(true);
+        }
+    }
+
+    /// The number of arrangements.
+    pub fn count(&self) -> C {
+        self.ways[0][0].clone()
+    }
+
+    /// Every arrangement, as whether each cell is filled.
+    pub fn arrangements(&self) -> impl Iterator<Item = Vec<bool>> + '_ {
+        let n = self.cells.len();
+        let mut stack = vec![];
+        if self.count() != C::from(0) {
+            stack.push((0, 0, vec![false; n]));
+        }
+        std::iter::from_fn(move || loop {
+            let (i, j, line) = stack.pop()?;
+            if i == n {
+                return Some(line);
+            }
+            // The run step is pushed last, so arrangements come out with runs as early as possible first.
+            for (step, next_i, next_j) in self.live_steps(i, j) {
+                let mut line = line.clone();
+                self.fill(&mut line, i, j, step);
+                stack.push((next_i, next_j, line));
+            }
+        })
+    }
+
+    /// A uniformly random arrangement, or `None` if there are none.
+    pub fn sample(&self, rng: &mut impl Rng) -> Option<Vec<bool>> {
+        let n = self.cells.len();
+        if self.count() == C::from(0) {
+            return None;
+        }
+        let mut line = vec![false; n];
+        let (mut i, mut j) = (0, 0);
+        while i < n {
+            // Pick each step with probability proportional to the arrangements it leads to.
+            let mut choice = self.ways[i][j].random_below(rng);
+            for (step, next_i, next_j) in self.live_steps(i, j) {
+                let ways = &self.ways[next_i][next_j];
+                if choice < *ways {
+                    self.fill(&mut line, i, j, step);
+                    (i, j) = (next_i, next_j);
+                    break;
+                }
+                choice -= ways;
+            }
+        }
+        Some(line)
+    }
+
+    /// For each cell, `Some(true)` if it is filled in every arrangement, `Some(false)` if it is
+    /// empty in every arrangement, and `None` otherwise. Returns `None` if there are no arrangements.
+    pub fn deductions(&self) -> Option<Vec<Option<bool>>> {
+        let (n, m) = (self.cells.len(), self.clues.len());
+        if self.count() == C::from(0) {
+            return None;
+        }
+        // Which states are reachable from the start along steps which lead to an arrangement.
+        let mut reachable = vec![vec![false; m + 1]; n + 1];
+        reachable[0][0] = true;
+        let mut can_fill = vec![false; n];
+        let mut can_empty = vec![false; n];
+        for i in 0..n {
+            for j in 0..=m {
+                if !reachable[i][j] {
+                    continue;
+                }
+                for (step, next_i, next_j) in self.live_steps(i, j) {
+                    reachable[next_i][next_j] = true;
+                    match step {
+                        Step::Empty => can_empty[i] = true,
+                        Step::Run => {
+                            let end = i + self.clues[j];
+                            can_fill[i..end].fill(true);
+                            if end < n {
+                                can_empty[end] = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Some(
+            can_fill
+                .into_iter()
+                .zip(can_empty)
+                .map(|(can_fill, can_empty)| match (can_fill, can_empty) {
+                    (true, false) => Some(true),
+                    (false, true) => Some(false),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Repeat the line `times` times, with an unknown cell between each copy, and repeat the clues
+/// to match.
+pub fn unfold(
+    cells: &[Option<bool>],
+    clues: &[usize],
+    times: usize,
+) -> (Vec<Option<bool>>, Vec<usize>) {
+    let cells = vec![cells; times].join(&None);
+    let clues = clues.repeat(times);
+    (cells, clues)
+}
+
+/// Solves a whole nonogram puzzle, given the clues for each row and each column.
+///
+/// Deduces as much as possible from each line in turn, and guesses when stuck. Returns `None`
+/// if there is no solution, and an arbitrary solution if there are several.
+pub fn solve(row_clues: &[Vec<usize>], column_clues: &[Vec<usize>]) -> Option<Grid<bool>> {
+    let grid = Grid::new(row_clues.len(), column_clues.len(), None);
+    let grid = solve_from(grid, row_clues, column_clues)?;
+    Some(grid.map(|cell| cell == &Some(true)))
+}
+
+fn solve_from(
+    mut grid: Grid<Option<bool>>,
+    row_clues: &[Vec<usize>],
+    column_clues: &[Vec<usize>],
+) -> Option<Grid<Option<bool>>> {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (row, clues) in row_clues.iter().enumerate() {
+            let deductions = LineSolver::<BigUint>::new(grid.row(row), clues).deductions()?;
+            for (cell, deduction) in grid.row_mut(row).iter_mut().zip(deductions) {
+                if cell.is_none() && deduction.is_some() {
+                    *cell = deduction;
+                    changed = true;
+                }
+            }
+        }
+        for (col, clues) in column_clues.iter().enumerate() {
+            let cells: Vec<Option<bool>> = grid.column(col).copied().collect();
+            let deductions = LineSolver::<BigUint>::new(&cells, clues).deductions()?;
+            for (cell, deduction) in grid.column_mut(col).into_iter().zip(deductions) {
+                if cell.is_none() && deduction.is_some() {
+                    *cell = deduction;
+                    changed = true;
+                }
+            }
+        }
+    }
+    let Some(unknown) = grid.position(Option::is_none) else {
+        return Some(grid);
+    };
+    [true, false].into_iter().find_map(|guess| {
+        let mut grid = grid.clone();
+        grid[unknown] = Some(guess);
+        solve_from(grid, row_clues, column_clues)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn cells(s: &str) -> Vec<Option<bool>> {
+        s.bytes()
+            .map(|c| match c {
+                b'#' => Some(true),
+                b'.' => Some(false),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn matches(line: &[bool], cells: &[Option<bool>], clues: &[usize]) -> bool {
+        let runs: Vec<usize> = line
+            .split(|&filled| !filled)
+            .map(<[bool]>::len)
+            .filter(|&len| len > 0)
+            .collect();
+        runs == clues
+            && line
+                .iter()
+                .zip(cells)
+                .all(|(&filled, cell)| cell.is_none_or(|cell| cell == filled))
+    }
+
+    #[test]
+    fn line() {
+        let solver = LineSolver::<usize>::new(&cells("?###????????"), &[3, 2, 1]);
+        assert_eq!(solver.count(), 10);
+        let arrangements: HashSet<Vec<bool>> = solver.arrangements().collect();
+        assert_eq!(arrangements.len(), 10);
+        assert!(arrangements
+            .iter()
+            .all(|line| matches(line, &cells("?###????????"), &[3, 2, 1])));
+        assert_eq!(solver.deductions(), Some(cells(".###.???????")));
+
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..20 {
+            assert!(arrangements.contains(&solver.sample(&mut rng).unwrap()));
+        }
+
+        let none = LineSolver::<usize>::new(&cells("#.#"), &[3]);
+        assert_eq!(none.count(), 0);
+        assert_eq!(none.arrangements().count(), 0);
+        assert_eq!(none.deductions(), None);
+        assert_eq!(none.sample(&mut rng), None);
+
+        let (cells, clues) = unfold(&cells("?###????????"), &[3, 2, 1], 5);
+        assert_eq!(
+            LineSolver::<BigUint>::new(&cells, &clues).count(),
+            BigUint::from(506250_u32)
+        );
+    }
+
+    #[test]
+    fn puzzle() {
+        // A heart.
+        let rows = [vec![1, 1], vec![5], vec![5], vec![3], vec![1]];
+        let columns = [vec![2], vec![4], vec![4], vec![4], vec![2]];
+        let solution = solve(&rows, &columns).unwrap();
+        let picture: Vec<String> = solution
+            .rows()
+            .map(|row| row.iter().map(|&c| if c { '#' } else { '.' }).collect())
+            .collect();
+        assert_eq!(picture, [".#.#.", "#####", "#####", ".###.", "..#.."]);
+
+        assert_eq!(solve(&[vec![2]], &[vec![1]]), None);
+    }
+}
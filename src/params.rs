@@ -0,0 +1,116 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use crate::Error;
+
+/// Named parameters which change what a day's solution computes, such as the number of steps
+/// to simulate, for running it against examples which use different values than the real puzzle.
+///
+/// Days read the parameters they understand when their solution is constructed. Any parameter
+/// which no day read is reported by [`Params::check_used`], so typos do not go unnoticed.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+    used: RefCell<BTreeSet<String>>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the parameter `name` to `value`, replacing any previous value.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl ToString) {
+        self.values.insert(name.into(), value.to_string());
+    }
+
+    /// Like [`Params::insert`], but by value.
+    pub fn with(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Whether there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of the parameter `name`, or `None` if it was not given.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T::Err: Display,
+    {
+        let Some(value) = self.values.get(name) else {
+            return Ok(None);
+        };
+        self.used.borrow_mut().insert(name.to_owned());
+        value
+            .parse()
+            .map(Some)
+            .map_err(|error| Error::new(format!("invalid parameter {name}={value}: {error}")))
+    }
+
+    /// Forget which parameters have been read, so that the same parameters can be checked against
+    /// another day.
+    pub(crate) fn reset_used(&self) {
+        self.used.borrow_mut().clear();
+    }
+
+    /// Fails if any parameter was given which was never read.
+    pub fn check_used(&self) -> Result<(), Error> {
+        let used = self.used.borrow();
+        match self.values.keys().find(|name| !used.contains(*name)) {
+            Some(name) => Err(Error::new(format!("unknown parameter {name}"))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K: Into<String>, V: ToString> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut params = Self::new();
+        for (name, value) in iter {
+            params.insert(name, value);
+        }
+        params
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (name, value) in &self.values {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Params;
+
+    #[test]
+    fn get() {
+        let params = Params::new().with("steps", 6).with("typo", "x");
+        assert_eq!(params.get::<usize>("steps"), Ok(Some(6)));
+        assert_eq!(params.get::<usize>("missing"), Ok(None));
+        assert_eq!(
+            params.check_used().unwrap_err().to_string(),
+            "unknown parameter typo"
+        );
+        assert_eq!(
+            params.get::<usize>("typo").unwrap_err().to_string(),
+            "invalid parameter typo=x: invalid digit found in string"
+        );
+        assert_eq!(params.check_used(), Ok(()));
+    }
+}
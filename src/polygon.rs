@@ -0,0 +1,190 @@
+//! Polygons with integer vertices, measured with the shoelace formula and Pick's theorem.
+
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed};
+
+use crate::{Direction, Point2};
+
+/// A signed integer type for polygon coordinates, such as `i64`, or `i128` for polygons whose
+/// doubled area would overflow `i64`.
+pub trait Coordinate: PrimInt + Signed + Integer {}
+
+impl<T: PrimInt + Signed + Integer> Coordinate for T {}
+
+/// A simple (not self-intersecting) polygon, given by its vertices in order. The last vertex is
+/// joined back to the first, and vertices may be repeated or lie along straight edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T> {
+    vertices: Vec<Point2<T>>,
+}
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+impl<T: Coordinate> Polygon<T> {
+    pub fn new(vertices: Vec<Point2<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// The polygon traced by starting at the origin and taking each step in turn, moving the
+    /// given distance in the given direction.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, T)>) -> Self {
+        let mut position = Point2::new(T::zero(), T::zero());
+        let mut vertices = vec![position];
+        for (direction, distance) in steps {
+            let offset = direction.offset();
+            let offset = Point2::new(T::from(offset.y).unwrap(), T::from(offset.x).unwrap());
+            position = position + offset * distance;
+            vertices.push(position);
+        }
+        if vertices.len() > 1 && vertices.last() == vertices.first() {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2<T>] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the signed area, by the shoelace formula. This is positive if the vertices go
+    /// clockwise as drawn on a grid (with y increasing downwards), and negative if they go
+    /// anticlockwise. Unlike the area, it is always an integer.
+    pub fn doubled_signed_area(&self) -> T {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .fold(T::zero(), |sum, term| sum + term)
+    }
+
+    /// The area, rounded down, which only matters if the polygon is not rectilinear.
+    pub fn area(&self) -> T {
+        self.doubled_signed_area().abs() / (T::one() + T::one())
+    }
+
+    /// How many lattice points are on the boundary, including the vertices.
+    pub fn boundary_points(&self) -> T {
+        self.edges()
+            .map(|(a, b)| (b.y - a.y).gcd(&(b.x - a.x)))
+            .fold(T::zero(), |sum, points| sum + points)
+    }
+
+    /// How many lattice points are strictly inside, by Pick's theorem: `A = I + B/2 - 1`.
+    pub fn interior_points(&self) -> T {
+        let two = T::one() + T::one();
+        (self.doubled_signed_area().abs() - self.boundary_points() + two) / two
+    }
+
+    /// Whether `point` is inside the polygon, on its boundary, or outside it.
+    pub fn contains(&self, point: Point2<T>) -> Containment {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+            let between = |p: T, q: T, r: T| p.min(q) <= r && r <= p.max(q);
+            if cross.is_zero() && between(a.x, b.x, point.x) && between(a.y, b.y, point.y) {
+                return Containment::Boundary;
+            }
+            // Cast a ray from the point towards +x, counting the edges it crosses. Each edge
+            // includes its lower end but not its upper end, so a ray through a vertex is only
+            // counted once.
+            if (a.y > point.y) != (b.y > point.y) {
+                // The edge crosses to the right of the point iff `cross` has the same sign as
+                // the edge's change in y.
+                let crosses_right = if b.y > a.y {
+                    cross.is_positive()
+                } else {
+                    cross.is_negative()
+                };
+                inside ^= crosses_right;
+            }
+        }
+        if inside {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon<T: Coordinate>(vertices: &[(T, T)]) -> Polygon<T> {
+        Polygon::new(vertices.iter().map(|&(y, x)| Point2::new(y, x)).collect())
+    }
+
+    #[test]
+    fn measure() {
+        let square = polygon::<i64>(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        assert_eq!(square.doubled_signed_area(), 8);
+        assert_eq!(square.area(), 4);
+        assert_eq!(square.boundary_points(), 8);
+        assert_eq!(square.interior_points(), 1);
+
+        let reversed = Polygon::new(square.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.doubled_signed_area(), -8);
+        assert_eq!(reversed.interior_points(), 1);
+
+        let triangle = polygon::<i64>(&[(0, 0), (3, 6), (0, 4)]);
+        assert_eq!(triangle.doubled_signed_area(), -12);
+        assert_eq!(triangle.boundary_points(), 3 + 1 + 4);
+        assert_eq!(triangle.interior_points(), 3);
+
+        let path = [
+            (Direction::East, 3),
+            (Direction::South, 2),
+            (Direction::West, 3),
+            (Direction::North, 2),
+        ];
+        assert_eq!(
+            Polygon::from_steps(path),
+            polygon(&[(0, 0), (0, 3), (2, 3), (2, 0)])
+        );
+
+        let huge = 1_i128 << 40;
+        let big = polygon::<i128>(&[(0, 0), (0, huge), (huge, huge), (huge, 0)]);
+        assert_eq!(big.area(), huge * huge);
+        assert_eq!(big.interior_points(), (huge - 1) * (huge - 1));
+    }
+
+    #[test]
+    fn containment() {
+        // A non-convex shape with diagonal edges and a horizontal edge level with a vertex.
+        let shape = polygon::<i64>(&[
+            (0, 0),
+            (0, 6),
+            (4, 10),
+            (8, 6),
+            (8, 4),
+            (4, 4),
+            (4, 2),
+            (8, 0),
+        ]);
+        assert_eq!(shape.contains(Point2::new(2, 2)), Containment::Inside);
+        assert_eq!(shape.contains(Point2::new(6, 3)), Containment::Outside);
+        assert_eq!(shape.contains(Point2::new(4, 3)), Containment::Boundary);
+        assert_eq!(shape.contains(Point2::new(2, 8)), Containment::Boundary);
+        assert_eq!(shape.contains(Point2::new(4, 11)), Containment::Outside);
+
+        let count = |containment| {
+            (-1..=9)
+                .flat_map(|y| (-1..=11).map(move |x| Point2::new(y, x)))
+                .filter(|&point| shape.contains(point) == containment)
+                .count() as i64
+        };
+        assert_eq!(count(Containment::Boundary), shape.boundary_points());
+        assert_eq!(count(Containment::Inside), shape.interior_points());
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+/// An exact rational number, always stored in lowest terms with a positive denominator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl Rational {
+    /// `numer / denom`. Panics if `denom` is zero.
+    pub fn new(numer: impl Into<BigInt>, denom: impl Into<BigInt>) -> Self {
+        let (numer, denom) = (numer.into(), denom.into());
+        assert!(!denom.is_zero(), "denominator must not be zero");
+        let gcd = numer.gcd(&denom);
+        let (mut numer, mut denom) = (numer / &gcd, denom / gcd);
+        if denom.is_negative() {
+            numer = -numer;
+            denom = -denom;
+        }
+        Self { numer, denom }
+    }
+
+    pub fn zero() -> Self {
+        Self::from(BigInt::zero())
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &BigInt {
+        &self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numer.is_negative()
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<BigInt> {
+        self.denom.is_one().then(|| self.numer.clone())
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Self {
+            numer: value,
+            denom: BigInt::one(),
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::from(BigInt::from(value))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying preserves the order.
+        (&self.numer * &other.denom).cmp(&(&other.numer * &self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -self.clone()
+    }
+}
+
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl $trait<&Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+
+        impl $trait<Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                $trait::$method(&self, &other)
+            }
+        }
+
+        impl $trait<&Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                $trait::$method(&self, other)
+            }
+        }
+
+        impl $trait<Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                $trait::$method(self, &other)
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, |a, b| Rational::new(
+    &a.numer * &b.denom + &b.numer * &a.denom,
+    &a.denom * &b.denom
+));
+impl_op!(Sub, sub, |a, b| Rational::new(
+    &a.numer * &b.denom - &b.numer * &a.denom,
+    &a.denom * &b.denom
+));
+impl_op!(Mul, mul, |a, b| Rational::new(
+    &a.numer * &b.numer,
+    &a.denom * &b.denom
+));
+// Panics if dividing by zero.
+impl_op!(Div, div, |a, b| Rational::new(
+    &a.numer * &b.denom,
+    &a.denom * &b.numer
+));
+
+/// Why a system of linear equations has no unique solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSystemError {
+    /// No values satisfy every equation.
+    Inconsistent,
+    /// Infinitely many values satisfy every equation.
+    Underdetermined,
+}
+
+impl fmt::Display for LinearSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearSystemError::Inconsistent => f.write_str("the equations are inconsistent"),
+            LinearSystemError::Underdetermined => {
+                f.write_str("the equations do not determine a unique solution")
+            }
+        }
+    }
+}
+
+/// Solve the linear equations `coefficients[i] · x = constants[i]` exactly, by Gauss-Jordan
+/// elimination. There may be more equations than unknowns.
+pub fn solve_linear(
+    mut coefficients: Vec<Vec<Rational>>,
+    mut constants: Vec<Rational>,
+) -> Result<Vec<Rational>, LinearSystemError> {
+    assert_eq!(coefficients.len(), constants.len());
+    let unknowns = coefficients.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    let mut pivot_rows = vec![None; unknowns];
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..coefficients.len()).find(|&row| !coefficients[row][col].is_zero())
+        else {
+            continue;
+        };
+        coefficients.swap(rank, pivot);
+        constants.swap(rank, pivot);
+        let factor = coefficients[rank][col].clone();
+        for value in &mut coefficients[rank] {
+            *value = &*value / &factor;
+        }
+        constants[rank] = &constants[rank] / &factor;
+        for row in 0..coefficients.len() {
+            let factor = coefficients[row][col].clone();
+            if row == rank || factor.is_zero() {
+                continue;
+            }
+            for i in col..unknowns {
+                let delta = &factor * &coefficients[rank][i];
+                coefficients[row][i] = &coefficients[row][i] - delta;
+            }
+            constants[row] = &constants[row] - &factor * &constants[rank];
+        }
+        pivot_rows[col] = Some(rank);
+        rank += 1;
+    }
+    if constants[rank..].iter().any(|constant| !constant.is_zero()) {
+        return Err(LinearSystemError::Inconsistent);
+    }
+    pivot_rows
+        .into_iter()
+        .map(|row| {
+            row.map(|row| constants[row].clone())
+                .ok_or(LinearSystemError::Underdetermined)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(numer: i64, denom: i64) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(3, 4), r(1, 2));
+        assert_eq!(r(2, 3) / r(-4, 3), r(-1, 2));
+        assert!(r(-1, 2) < r(1, 3));
+        assert_eq!(r(6, 3).to_integer(), Some(BigInt::from(2)));
+        assert_eq!(r(1, 3).to_integer(), None);
+        assert_eq!(r(-4, 6).to_string(), "-2/3");
+    }
+
+    #[test]
+    fn linear() {
+        let rows = |rows: &[&[i64]]| {
+            rows.iter()
+                .map(|row| row.iter().map(|&x| Rational::from(x)).collect())
+                .collect()
+        };
+        let constants = |values: &[i64]| values.iter().map(|&x| Rational::from(x)).collect();
+        // x + y = 3, x - y = 1, 2x + 2y = 6
+        assert_eq!(
+            solve_linear(rows(&[&[1, 1], &[1, -1], &[2, 2]]), constants(&[3, 1, 6])),
+            Ok(vec![r(2, 1), r(1, 1)])
+        );
+        assert_eq!(
+            solve_linear(rows(&[&[0, 2], &[3, 0]]), constants(&[1, 1])),
+            Ok(vec![r(1, 3), r(1, 2)])
+        );
+        assert_eq!(
+            solve_linear(rows(&[&[1, 1], &[2, 2]]), constants(&[1, 2])),
+            Err(LinearSystemError::Underdetermined)
+        );
+        assert_eq!(
+            solve_linear(rows(&[&[1, 1], &[2, 2]]), constants(&[1, 3])),
+            Err(LinearSystemError::Inconsistent)
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use std::{
+    fmt,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    days::{Alternative, GraphsFn, ReportFn},
+    Error, Params, Solution,
+};
+
+const YEAR: i32 = 2023;
+
+/// Where the puzzle input for the given day is cached.
+pub fn input_path(day: u32) -> PathBuf {
+    PathBuf::from_iter(["inputs", &YEAR.to_string(), &format!("{day}.txt")])
+}
+
+/// Where the known-correct answers for the given day are stored.
+///
+/// The file has the part 1 answer on the first line and the part 2 answer on the second line.
+/// Either line may be empty if that answer is not known yet.
+pub fn answers_path(day: u32) -> PathBuf {
+    PathBuf::from_iter(["answers", &YEAR.to_string(), &format!("{day}.txt")])
+}
+
+fn cache_path(day: u32) -> PathBuf {
+    PathBuf::from_iter(["cache", &YEAR.to_string(), &format!("{day}.json")])
+}
+
+/// Read the known-correct answers for the given day.
+pub fn read_answers(day: u32) -> [Option<String>; 2] {
+    let answers = std::fs::read_to_string(answers_path(day)).unwrap_or_default();
+    let mut lines = answers
+        .lines()
+        .map(str::trim)
+        .map(|line| (!line.is_empty()).then(|| line.to_owned()));
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// Record a known-correct answer for the given day and part.
+fn record_answer(day: u32, part: u32, answer: &str) -> std::io::Result<()> {
+    let mut answers = read_answers(day);
+    let slot = &mut answers[part as usize - 1];
+    if slot.as_deref() == Some(answer) {
+        return Ok(());
+    }
+    *slot = Some(answer.to_owned());
+    let path = answers_path(day);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    let [part_1, part_2] = answers.map(Option::unwrap_or_default);
+    std::fs::write(path, format!("{part_1}\n{part_2}\n"))
+}
+
+/// The outcome of running one part of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The answer matches the stored answer.
+    Pass,
+    /// The answer does not match the stored answer.
+    Fail,
+    /// There is no stored answer to compare against.
+    Unknown,
+    /// The answer was accepted by the AoC website.
+    Correct,
+    /// The answer was rejected by the AoC website.
+    Incorrect,
+    /// The solution (or fetching the input, or submitting the answer) failed.
+    Error,
+}
+
+/// The result of running one part of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    /// In seconds. Includes parsing, if this part was the first to need the parsed input.
+    #[serde(serialize_with = "as_secs")]
+    pub duration: Option<Duration>,
+    pub status: Status,
+    /// The stored answer, if the answer did not match it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// What went wrong, if the status is [`Status::Error`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn as_secs<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration
+        .map(|duration| duration.as_secs_f64())
+        .serialize(serializer)
+}
+
+impl PartResult {
+    fn error(day: u32, part: u32, error: impl fmt::Display) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            duration: None,
+            status: Status::Error,
+            expected: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    /// Whether this part went wrong.
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.status,
+            Status::Fail | Status::Incorrect | Status::Error
+        )
+    }
+}
+
+impl fmt::Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { day, part, .. } = self;
+        let answer = self.answer.as_deref().unwrap_or_default();
+        let status = match self.status {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Unknown => "UNKNOWN",
+            Status::Correct => "CORRECT",
+            Status::Incorrect => "INCORRECT",
+            Status::Error => "ERROR",
+        };
+        write!(f, "Day {day} part {part}: {status}")?;
+        match (&self.error, &self.expected) {
+            (Some(error), _) => write!(f, " ({error})"),
+            (None, Some(expected)) => write!(f, " (expected {expected}, got {answer})"),
+            (None, None) => write!(f, " ({answer})"),
+        }
+    }
+}
+
+/// Run one part of a solution, timing it.
+fn solve(day: u32, solution: &mut dyn Solution, part: u32, input: &str) -> PartResult {
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part_1(input),
+        2 => solution.part_2(input),
+        _ => Err(Error::new(format!("there is no part {part}"))),
+    };
+    let duration = start.elapsed();
+    match answer {
+        Ok(answer) => PartResult {
+            day,
+            part,
+            answer: Some(answer),
+            duration: Some(duration),
+            status: Status::Unknown,
+            expected: None,
+            error: None,
+        },
+        Err(error) => PartResult {
+            duration: Some(duration),
+            ..PartResult::error(day, part, error)
+        },
+    }
+}
+
+/// Run a day's solution against its locally cached input, without using the network.
+pub fn run(day: u32, solution: &mut dyn Solution, parts: &[u32]) -> Vec<PartResult> {
+    let path = input_path(day);
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(error) => {
+            let error = format!("could not read {}: {error}", path.display());
+            return parts
+                .iter()
+                .map(|&part| PartResult::error(day, part, &error))
+                .collect();
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| solve(day, solution, part, &input))
+        .collect()
+}
+
+/// The result of running one implementation of a part in [`cross_check`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CrossCheckResult {
+    pub day: u32,
+    pub part: u32,
+    /// `main`, or the name of the alternative implementation.
+    pub implementation: String,
+    pub answer: Option<String>,
+    #[serde(serialize_with = "as_secs")]
+    pub duration: Option<Duration>,
+    /// Whether an alternative gave the same answer as the main implementation. `None` for the
+    /// main implementation itself, or if either failed.
+    pub agrees: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CrossCheckResult {
+    /// Whether this implementation failed or disagreed with the main one.
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.agrees == Some(false)
+    }
+}
+
+impl fmt::Display for CrossCheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            day,
+            part,
+            implementation,
+            ..
+        } = self;
+        write!(f, "Day {day} part {part} ({implementation}): ")?;
+        let answer = self.answer.as_deref().unwrap_or_default();
+        match (&self.error, self.agrees) {
+            (Some(error), _) => write!(f, "ERROR ({error})")?,
+            (None, Some(true)) => write!(f, "AGREES ({answer})")?,
+            (None, Some(false)) => write!(f, "DISAGREES ({answer})")?,
+            (None, None) => write!(f, "{answer}")?,
+        }
+        if let Some(duration) = self.duration {
+            write!(f, " in {duration:.2?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the main implementation and every alternative implementation of each of `parts` which has
+/// alternatives against a day's locally cached input, comparing the alternatives' answers to the
+/// main one's.
+pub fn cross_check(
+    day: u32,
+    solution: &mut dyn Solution,
+    alternatives: &[Alternative],
+    params: &Params,
+    parts: &[u32],
+) -> Vec<CrossCheckResult> {
+    let path = input_path(day);
+    let input = std::fs::read_to_string(&path)
+        .map_err(|error| format!("could not read {}: {error}", path.display()));
+    let mut results = vec![];
+    for &part in parts {
+        let alternatives = alternatives
+            .iter()
+            .filter(|alternative| alternative.part == part)
+            .collect::<Vec<_>>();
+        if alternatives.is_empty() {
+            continue;
+        }
+        let input = match &input {
+            Ok(input) => input,
+            Err(error) => {
+                results.push(CrossCheckResult {
+                    day,
+                    part,
+                    implementation: "main".to_string(),
+                    answer: None,
+                    duration: None,
+                    agrees: None,
+                    error: Some(error.clone()),
+                });
+                continue;
+            }
+        };
+        let main = solve(day, solution, part, input);
+        let main_answer = main.answer.clone();
+        results.push(CrossCheckResult {
+            day,
+            part,
+            implementation: "main".to_string(),
+            answer: main.answer,
+            duration: main.duration,
+            agrees: None,
+            error: main.error,
+        });
+        for alternative in alternatives {
+            let start = Instant::now();
+            let answer =
+                std::panic::catch_unwind(AssertUnwindSafe(|| (alternative.run)(input, params)))
+                    .unwrap_or_else(|payload| Err(Error::from_panic(alternative.name, &*payload)))
+                    .map_err(|error| error.locate(input));
+            let duration = start.elapsed();
+            let (answer, error) = match answer {
+                Ok(answer) => (Some(answer), None),
+                Err(error) => (None, Some(error.to_string())),
+            };
+            let agrees = main_answer
+                .as_ref()
+                .zip(answer.as_ref())
+                .map(|(main, answer)| main == answer);
+            results.push(CrossCheckResult {
+                day,
+                part,
+                implementation: alternative.name.to_string(),
+                answer,
+                duration: Some(duration),
+                agrees,
+                error,
+            });
+        }
+    }
+    results
+}
+
+/// Draw a day's locally cached input with its `graphs` function, writing each diagram into `dir`
+/// as `dayN-NAME.dot`, and also as `dayN-NAME.mmd` if `mermaid` is set. Returns the paths written.
+pub fn export_graphs(
+    day: u32,
+    graphs: GraphsFn,
+    dir: &Path,
+    mermaid: bool,
+) -> Result<Vec<PathBuf>, Error> {
+    let path = input_path(day);
+    let input = std::fs::read_to_string(&path)
+        .map_err(|error| Error::new(format!("could not read {}: {error}", path.display())))?;
+    let diagrams = std::panic::catch_unwind(|| graphs(&input))
+        .map_err(|payload| Error::from_panic("graph builder", &*payload))?
+        .map_err(|error| error.locate(&input))?;
+    std::fs::create_dir_all(dir)
+        .map_err(|error| Error::new(format!("could not create {}: {error}", dir.display())))?;
+    let mut written = vec![];
+    for diagram in diagrams {
+        let mut files = vec![("dot", diagram.to_dot())];
+        if mermaid {
+            files.push(("mmd", diagram.to_mermaid()));
+        }
+        for (extension, contents) in files {
+            let path = dir.join(format!("day{day}-{}.{extension}", diagram.name));
+            std::fs::write(&path, contents).map_err(|error| {
+                Error::new(format!("could not write {}: {error}", path.display()))
+            })?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// Describe how a day solves its locally cached input with its `report` function.
+pub fn report(day: u32, report: ReportFn, params: &Params) -> Result<String, Error> {
+    let path = input_path(day);
+    let input = std::fs::read_to_string(&path)
+        .map_err(|error| Error::new(format!("could not read {}: {error}", path.display())))?;
+    report(&input, params).map_err(|error| error.locate(&input))
+}
+
+/// Like [`run`], but also compare the results to the locally stored answers.
+pub fn verify(day: u32, solution: &mut dyn Solution, parts: &[u32]) -> Vec<PartResult> {
+    let answers = read_answers(day);
+    let mut results = run(day, solution, parts);
+    for result in &mut results {
+        if let (Some(answer), Some(expected)) = (&result.answer, &answers[result.part as usize - 1])
+        {
+            if answer == expected {
+                result.status = Status::Pass;
+            } else {
+                result.status = Status::Fail;
+                result.expected = Some(expected.clone());
+            }
+        }
+    }
+    results
+}
+
+/// Run a day's solution against the real input, fetching it with the session cookie in
+/// `.session.txt` if it is not cached, and submit the answers. Correct answers are stored
+/// for later use by [`verify`].
+pub fn submit(day: u32, solution: &mut dyn Solution, parts: &[u32]) -> Vec<PartResult> {
+    let (session, cache_path, input) = match prepare_submission(day) {
+        Ok(prepared) => prepared,
+        Err(error) => {
+            return parts
+                .iter()
+                .map(|&part| PartResult::error(day, part, &error))
+                .collect();
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            let mut result = solve(day, solution, part, &input);
+            let Some(answer) = &result.answer else {
+                return result;
+            };
+            match aoc_driver::post_answer(
+                &session,
+                YEAR,
+                day as i32,
+                part as i32,
+                Some(&cache_path),
+                answer,
+            ) {
+                Ok(()) => {
+                    result.status = Status::Correct;
+                    if let Err(error) = record_answer(day, part, answer) {
+                        eprintln!("Could not record day {day} part {part} answer: {error}");
+                    }
+                }
+                Err(aoc_driver::error::Error::Incorrect) => result.status = Status::Incorrect,
+                Err(error) => {
+                    result.status = Status::Error;
+                    result.error = Some(format!("could not submit answer: {error:?}"));
+                }
+            }
+            result
+        })
+        .collect()
+}
+
+/// Read the session cookie, create the cache directories, and get the day's input, fetching it if
+/// it is not cached. Returns the session cookie, the answer cache path, and the input.
+fn prepare_submission(day: u32) -> Result<(String, PathBuf, String), String> {
+    let session = std::fs::read_to_string(".session.txt")
+        .map_err(|error| format!("could not read .session.txt: {error}"))?;
+    let path = input_path(day);
+    let cache_path = cache_path(day);
+    for dir in [path.parent().unwrap(), cache_path.parent().unwrap()] {
+        std::fs::create_dir_all(dir)
+            .map_err(|error| format!("could not create {}: {error}", dir.display()))?;
+    }
+    let input = aoc_driver::get_input_or_file(session.trim(), YEAR, day as i32, &path)
+        .map_err(|error| format!("could not get input: {error:?}"))?;
+    Ok((session.trim().to_string(), cache_path, input))
+}
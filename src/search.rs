@@ -0,0 +1,258 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rayon::prelude::*;
+
+/// The result of a successful shortest-path search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPath<S, C> {
+    /// The total cost of the path.
+    pub cost: C,
+    /// Every state along the path, from the start state to the goal state (inclusive).
+    pub path: Vec<S>,
+}
+
+/// Finds the cheapest path from `start` to a state matching `is_goal` using Dijkstra's algorithm.
+///
+/// `successors` yields each state reachable in one step from the given state, with the cost of that step.
+/// Costs must be non-negative. Returns `None` if no goal state is reachable.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, is_goal, |_| C::default())
+}
+
+/// Finds the cheapest path from `start` to a state matching `is_goal` using A* search.
+///
+/// `successors` yields each state reachable in one step from the given state, with the cost of that step.
+/// Costs must be non-negative, and `heuristic` must never overestimate the remaining cost to a goal state.
+/// Returns `None` if no goal state is reachable.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<ShortestPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Each state seen is given an index into these,
+    // holding the state, its best known cost, and the index of its predecessor on the best known path.
+    let mut states: Vec<(S, C, usize)> = vec![(start.clone(), C::default(), usize::MAX)];
+    let mut indices: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    // (estimated total cost, cost so far, state index)
+    let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(C::default()), 0)]);
+
+    while let Some((_, Reverse(cost), idx)) = queue.pop() {
+        if cost > states[idx].1 {
+            // A cheaper path to this state was already found.
+            continue;
+        }
+        if is_goal(&states[idx].0) {
+            let mut path = vec![];
+            let mut idx = idx;
+            while idx != usize::MAX {
+                let (state, _, prev) = &states[idx];
+                path.push(state.clone());
+                idx = *prev;
+            }
+            path.reverse();
+            return Some(ShortestPath { cost, path });
+        }
+        for (next, step_cost) in successors(&states[idx].0) {
+            let next_cost = cost + step_cost;
+            let next_idx = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_idx = *entry.get();
+                    if next_cost >= states[next_idx].1 {
+                        continue;
+                    }
+                    states[next_idx].1 = next_cost;
+                    states[next_idx].2 = idx;
+                    next_idx
+                }
+                Entry::Vacant(entry) => {
+                    let next_idx = states.len();
+                    states.push((entry.key().clone(), next_cost, idx));
+                    entry.insert(next_idx);
+                    next_idx
+                }
+            };
+            let estimate = next_cost + heuristic(&states[next_idx].0);
+            queue.push((Reverse(estimate), Reverse(next_cost), next_idx));
+        }
+    }
+    None
+}
+
+/// Finds the length of the longest path from `start` to `goal` which visits no node more than once.
+///
+/// `edges[node]` lists the `(next node, length)` edges out of each node, so an undirected graph
+/// must list each edge in both directions. There may be at most 64 nodes, so that the visited
+/// nodes fit in a `u64` bitmask. Returns `None` if `goal` is unreachable from `start`.
+///
+/// The search is a depth-first search, which branches in parallel near the root, and abandons a
+/// path once even the longest edge into every unvisited node could not make it beat the best
+/// path found so far.
+pub fn longest_path(edges: &[Vec<(usize, usize)>], start: usize, goal: usize) -> Option<usize> {
+    assert!(edges.len() <= 64, "too many nodes for a u64 bitmask");
+    let mut longest_in = vec![0; edges.len()];
+    for &(next, length) in edges.iter().flatten() {
+        longest_in[next] = longest_in[next].max(length);
+    }
+    // If only one node leads to the goal, a path which reaches it without going straight to the
+    // goal can never get there.
+    let mut into_goal =
+        (0..edges.len()).filter(|&node| edges[node].iter().any(|&(next, _)| next == goal));
+    let last = into_goal.next().filter(|_| into_goal.next().is_none());
+    let search = LongestPath {
+        edges,
+        goal,
+        last,
+        longest_in: &longest_in,
+        best: AtomicUsize::new(0),
+        found: AtomicUsize::new(0),
+    };
+    let bound = longest_in.iter().sum::<usize>() - longest_in[start];
+    search.search(start, 1 << start, 0, bound, 0);
+    (search.found.load(Ordering::Relaxed) > 0).then(|| search.best.load(Ordering::Relaxed))
+}
+
+struct LongestPath<'a> {
+    edges: &'a [Vec<(usize, usize)>],
+    goal: usize,
+    /// The only node with an edge to the goal, if there is just one.
+    last: Option<usize>,
+    /// The longest edge into each node.
+    longest_in: &'a [usize],
+    best: AtomicUsize,
+    /// How many paths to the goal have been found (only whether it is zero matters).
+    found: AtomicUsize,
+}
+
+impl LongestPath<'_> {
+    /// Search branches this close to the start in parallel.
+    const PARALLEL_DEPTH: usize = 6;
+
+    /// Continue a path at `node` which has visited `visited` and has length `length`. `bound` is
+    /// the sum of `longest_in` for the unvisited nodes.
+    fn search(&self, node: usize, visited: u64, length: usize, bound: usize, depth: usize) {
+        if node == self.goal {
+            self.found.fetch_add(1, Ordering::Relaxed);
+            self.best.fetch_max(length, Ordering::Relaxed);
+            return;
+        }
+        if self.found.load(Ordering::Relaxed) > 0
+            && length + bound <= self.best.load(Ordering::Relaxed)
+        {
+            return;
+        }
+        let next = |&(next, edge): &(usize, usize)| {
+            if visited & (1 << next) == 0 && (Some(node) != self.last || next == self.goal) {
+                self.search(
+                    next,
+                    visited | (1 << next),
+                    length + edge,
+                    bound - self.longest_in[next],
+                    depth + 1,
+                );
+            }
+        };
+        if depth < Self::PARALLEL_DEPTH {
+            self.edges[node].par_iter().for_each(next);
+        } else {
+            self.edges[node].iter().for_each(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{astar, dijkstra, longest_path};
+    use crate::Grid;
+
+    #[test]
+    fn grid_path() {
+        let grid = Grid::parse_with("131\n191\n111", |b| (b - b'0') as u32);
+        let goal = (2, 2);
+        let successors = |&position: &(usize, usize)| {
+            grid.neighbors4(position)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+        let result = dijkstra((0, 0), successors, |&position| position == goal).unwrap();
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+
+        let manhattan = |&(y, x): &(usize, usize)| (goal.0 - y + goal.1 - x) as u32;
+        let result = astar((0, 0), successors, |&position| position == goal, manhattan).unwrap();
+        assert_eq!(result.cost, 4);
+
+        assert_eq!(dijkstra((0, 0), successors, |_| false), None);
+    }
+
+    #[test]
+    fn longest() {
+        fn brute_force(
+            edges: &[Vec<(usize, usize)>],
+            node: usize,
+            goal: usize,
+            visited: u64,
+        ) -> Option<usize> {
+            if node == goal {
+                return Some(0);
+            }
+            edges[node]
+                .iter()
+                .filter(|&&(next, _)| visited & (1 << next) == 0)
+                .filter_map(|&(next, length)| {
+                    Some(length + brute_force(edges, next, goal, visited | (1 << next))?)
+                })
+                .max()
+        }
+
+        // 0 -1- 1 -1- 3, with a detour 0 -2- 2 -2- 1, and a one-way shortcut 2 -> 3.
+        let edges = vec![
+            vec![(1, 1), (2, 2)],
+            vec![(0, 1), (2, 2), (3, 1)],
+            vec![(0, 2), (1, 2), (3, 1)],
+            vec![],
+        ];
+        assert_eq!(longest_path(&edges, 0, 3), Some(5));
+        assert_eq!(longest_path(&edges, 3, 0), None);
+        assert_eq!(longest_path(&edges, 0, 0), Some(0));
+
+        let mut rng = StdRng::seed_from_u64(23);
+        for _ in 0..50 {
+            let n = rng.gen_range(2..10);
+            let mut edges = vec![vec![]; n];
+            for from in 0..n {
+                for to in 0..n {
+                    if from != to && rng.gen_bool(0.3) {
+                        edges[from].push((to, rng.gen_range(1..10)));
+                    }
+                }
+            }
+            assert_eq!(
+                longest_path(&edges, 0, n - 1),
+                brute_force(&edges, 0, n - 1, 1)
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    fmt::Display,
+    panic::{AssertUnwindSafe, RefUnwindSafe, UnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{Error, PartOutput, PreParsed, SingleFunction, TryPreParsed, TrySingleFunction};
+
+/// A solution to both parts of a day's puzzle, which can be run without knowing how it is structured.
+///
+/// Each part may only be run once per solution, and both parts must be given the same input.
+pub trait Solution {
+    fn part_1(&mut self, input: &str) -> Result<String, Error>;
+    fn part_2(&mut self, input: &str) -> Result<String, Error>;
+
+    /// How long each step of the solution took, for the steps that have been run.
+    fn timings(&self) -> Timings;
+}
+
+/// How long each step of a solution took.
+///
+/// Solutions which parse the input separately for each part have no parse time, and
+/// solutions which compute both parts in a single function report that function's time as
+/// the parse time, since it is shared by both parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+/// A solution with a separate function for each part, each of which parses the input itself.
+pub struct SeparateFunctions<F1, F2> {
+    part_1: Option<F1>,
+    part_2: Option<F2>,
+    timings: Timings,
+}
+
+impl<F1, F2, R1, R2> SeparateFunctions<F1, F2>
+where
+    F1: FnOnce(&str) -> R1,
+    F2: FnOnce(&str) -> R2,
+{
+    pub fn new(part_1: F1, part_2: F2) -> Self {
+        Self {
+            part_1: Some(part_1),
+            part_2: Some(part_2),
+            timings: Timings::default(),
+        }
+    }
+}
+
+fn run_once<R: Display>(
+    f: &mut Option<impl FnOnce(&str) -> R>,
+    elapsed: &mut Option<Duration>,
+    input: &str,
+    name: &str,
+) -> Result<String, Error> {
+    let f = f
+        .take()
+        .ok_or_else(|| Error::new(format!("{name} was already run")))?;
+    let start = Instant::now();
+    let output = std::panic::catch_unwind(AssertUnwindSafe(|| f(input)));
+    *elapsed = Some(start.elapsed());
+    output
+        .map(|output| output.to_string())
+        .map_err(|payload| Error::from_panic(name, &*payload))
+}
+
+impl<F1, F2, R1, R2> Solution for SeparateFunctions<F1, F2>
+where
+    F1: FnOnce(&str) -> R1,
+    F2: FnOnce(&str) -> R2,
+    R1: Display,
+    R2: Display,
+{
+    fn part_1(&mut self, input: &str) -> Result<String, Error> {
+        run_once(
+            &mut self.part_1,
+            &mut self.timings.part_1,
+            input,
+            "part 1 solution function",
+        )
+    }
+
+    fn part_2(&mut self, input: &str) -> Result<String, Error> {
+        run_once(
+            &mut self.part_2,
+            &mut self.timings.part_2,
+            input,
+            "part 2 solution function",
+        )
+    }
+
+    fn timings(&self) -> Timings {
+        self.timings
+    }
+}
+
+impl<PF, P, F1, F2, R1, R2> Solution for PreParsed<PF, P, F1, F2, R1, R2>
+where
+    PF: FnOnce(&str) -> P,
+    F1: FnOnce(&P) -> R1 + UnwindSafe,
+    F2: FnOnce(&P) -> R2 + UnwindSafe,
+    P: RefUnwindSafe,
+    R1: Display,
+    R2: Display,
+{
+    fn part_1(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_1()(input).map(ToString::to_string)
+    }
+
+    fn part_2(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_2()(input).map(ToString::to_string)
+    }
+
+    fn timings(&self) -> Timings {
+        self.timings()
+    }
+}
+
+impl<PF, P, F1, F2, O1, O2> Solution for TryPreParsed<PF, P, F1, F2, O1, O2>
+where
+    PF: FnOnce(&str) -> Result<P, Error>,
+    F1: FnOnce(&P) -> O1 + UnwindSafe,
+    F2: FnOnce(&P) -> O2 + UnwindSafe,
+    O1: PartOutput,
+    O2: PartOutput,
+    O1::Answer: Display,
+    O2::Answer: Display,
+    P: RefUnwindSafe,
+{
+    fn part_1(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_1()(input).map(ToString::to_string)
+    }
+
+    fn part_2(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_2()(input).map(ToString::to_string)
+    }
+
+    fn timings(&self) -> Timings {
+        self.timings()
+    }
+}
+
+impl<F, A, T> Solution for SingleFunction<F, A>
+where
+    T: Into<Option<A>>,
+    F: FnOnce(&str) -> (A, T) + UnwindSafe,
+    A: Display,
+{
+    fn part_1(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_1()(input).map(ToString::to_string)
+    }
+
+    fn part_2(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_2()(input).map(ToString::to_string)
+    }
+
+    fn timings(&self) -> Timings {
+        Timings {
+            parse: self.elapsed(),
+            ..Timings::default()
+        }
+    }
+}
+
+impl<F, A, T> Solution for TrySingleFunction<F, A>
+where
+    T: Into<Option<A>>,
+    F: FnOnce(&str) -> Result<(A, T), Error>,
+    A: Display,
+{
+    fn part_1(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_1()(input).map(ToString::to_string)
+    }
+
+    fn part_2(&mut self, input: &str) -> Result<String, Error> {
+        self.try_part_2()(input).map(ToString::to_string)
+    }
+
+    fn timings(&self) -> Timings {
+        Timings {
+            parse: self.elapsed(),
+            ..Timings::default()
+        }
+    }
+}